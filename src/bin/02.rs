@@ -2,18 +2,26 @@ advent_of_code::solution!(2);
 #[allow(unused_imports)]
 use advent_of_code::prelude::*;
 
-fn parse_input(input: &str) -> Vec<std::ops::RangeInclusive<usize>> {
-    input
-        .split(",")
-        .map(|input| {
-            let (left, right) = input.trim().split_once("-").unwrap();
-
-            let left: usize = left.parse().unwrap();
-            let right: usize = right.parse().unwrap();
-
-            left..=right
-        })
-        .collect_vec()
+use std::ops::RangeInclusive;
+
+use advent_of_code::components::interval::IntervalSet;
+
+/// Overlapping ranges are merged, so an ID listed in several ranges is only counted once.
+fn parse_input(input: &str) -> Vec<RangeInclusive<usize>> {
+    let range = parse::map(
+        parse::pair(
+            parse::preceded(
+                parse::spaces(),
+                parse::terminated(parse::unsigned(), parse::tag("-")),
+            ),
+            parse::unsigned(),
+        ),
+        |(left, right)| left..=right,
+    );
+
+    let ranges = parse::parse_all(input.trim(), parse::separated(range, ",")).unwrap();
+
+    IntervalSet::from_iter(ranges).iter().collect_vec()
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
@@ -85,6 +93,12 @@ mod tests {
         assert_eq!(part_one("11-22"), Some(33));
     }
 
+    #[test]
+    fn overlapping_ranges_count_once() {
+        assert_eq!(part_one("11-22, 20-33"), Some(66));
+        assert_eq!(part_two("11-22, 11-22"), Some(33));
+    }
+
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file("examples", DAY));
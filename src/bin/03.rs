@@ -98,14 +98,14 @@ pub fn part_one(input: &str) -> Option<u64> {
                 max_two(i)
             })
             .map(|i| i.0 * 10 + i.1)
-            .sum::<u64>() as u64,
+            .sum::<u64>(),
     )
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
     let input = parse_input(input);
 
-    Some(input.iter().map(|i| max_of(i)).sum::<u64>() as u64)
+    Some(input.iter().map(|i| max_of(i)).sum::<u64>())
 }
 
 #[cfg(test)]
@@ -1,82 +1,40 @@
 advent_of_code::solution!(5);
-use std::ops::RangeInclusive;
 
 #[allow(unused_imports)]
 use advent_of_code::prelude::*;
 
-fn parse_input(input: &str) -> (Vec<std::ops::RangeInclusive<u64>>, Vec<u64>) {
-    let (first, last) = input.split_once("\n\n").unwrap();
-
-    let first = first
-        .lines()
-        .map(|line| {
-            let (left, right) = line.split_once("-").unwrap();
-            left.parse::<u64>().unwrap()..=right.parse::<u64>().unwrap()
-        })
-        .collect_vec();
-
-    let last = last
-        .lines()
-        .map(|l| l.parse::<u64>().unwrap())
-        .collect_vec();
+use advent_of_code::components::interval::IntervalSet;
 
-    (first, last)
+fn parse_input(input: &str) -> (Vec<std::ops::RangeInclusive<u64>>, Vec<u64>) {
+    let range = parse::map(
+        parse::pair(
+            parse::terminated(parse::unsigned(), parse::tag("-")),
+            parse::unsigned(),
+        ),
+        |(left, right)| left..=right,
+    );
+
+    parse::parse_all(
+        input,
+        parse::pair(
+            parse::terminated(parse::lines(range), parse::tag("\n\n")),
+            parse::lines(parse::unsigned()),
+        ),
+    )
+    .unwrap()
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
     let (database, ingredients) = parse_input(input);
+    let fresh = IntervalSet::from_iter(database);
 
-    Some(
-        ingredients
-            .iter()
-            .filter(|n| database.iter().any(|d| d.contains(n)))
-            .count() as u64,
-    )
-}
-
-fn combine(left: &RangeInclusive<u64>, right: &RangeInclusive<u64>) -> Option<RangeInclusive<u64>> {
-    ///// AAAA
-    /////   BBBB
-
-    if left.contains(right.start())
-        || left.contains(right.end())
-        || right.contains(left.start())
-        || right.contains(left.end())
-    {
-        // overlap
-        let start = left.start().min(right.start());
-        let end = left.end().max(right.end());
-
-        return Some(*start..=*end);
-    }
-
-    None
+    Some(ingredients.iter().filter(|n| fresh.contains(**n)).count() as u64)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let (mut input, _) = parse_input(input);
+    let (database, _) = parse_input(input);
 
-    let mut count = input.len();
-    loop {
-        let mut collapsed = Vec::new();
-        'outer: for left in input.into_iter() {
-            for right in collapsed.iter_mut() {
-                if let Some(new) = combine(&left, right) {
-                    *right = new;
-                    continue 'outer;
-                }
-            }
-
-            collapsed.push(left);
-        }
-        input = collapsed;
-        if count == input.len() {
-            break;
-        }
-        count = input.len();
-    }
-
-    Some(input.iter().map(|c| 1 + c.end() - c.start()).sum())
+    Some(IntervalSet::from_iter(database).covered_len())
 }
 
 #[cfg(test)]
@@ -94,39 +52,4 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file("examples", DAY));
         assert_eq!(result, Some(14));
     }
-
-    #[test]
-    fn test_combine() {
-        assert_eq!(combine(&(12..=18), &(10..=14)), Some(10..=18));
-        assert_eq!(combine(&(10..=18), &(16..=20)), Some(10..=20));
-    }
-
-    #[test]
-    fn test_0_range() {
-        assert_eq!(
-            combine(
-                &(354113252785914..=354113252785914),
-                &(354113252785914..=359458697423182)
-            ),
-            Some(354113252785914..=359458697423182)
-        );
-        assert_eq!(
-            combine(
-                &(354113252785914..=359458697423182),
-                &(354113252785914..=354113252785914),
-            ),
-            Some(354113252785914..=359458697423182)
-        );
-    }
-
-    #[test]
-    fn test_failing() {
-        assert_eq!(
-            combine(
-                &(418784088040056..=419809022460311),
-                &(418978112767017..=419296794005487),
-            ),
-            Some(418784088040056..=419809022460311)
-        );
-    }
 }
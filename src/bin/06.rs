@@ -1,25 +1,19 @@
 advent_of_code::solution!(6);
+use advent_of_code::grid::{transform::Transform, vec_grid::VecGrid};
 #[allow(unused_imports)]
 use advent_of_code::prelude::*;
 
-fn rotate<T: Copy>(v: Vec<Vec<T>>) -> Vec<Vec<T>> {
-    let len = v.first().unwrap().len();
-
-    (0..len)
-        .map(|n| v.iter().map(|l| *l.get(n).unwrap()).collect_vec())
-        .collect_vec()
-}
-
 pub fn part_one(input: &str) -> Option<u64> {
     let lines = input
         .lines()
         .map(|line| line.split_whitespace().collect_vec())
         .collect_vec();
 
-    let input = rotate(lines);
+    let input = VecGrid::from_rows(lines).transpose();
 
     let input = input
-        .into_iter()
+        .rows()
+        .map(|v| v.to_vec())
         .map(|mut v| {
             let symbol = v.pop().unwrap().chars().next().unwrap();
 
@@ -46,7 +40,8 @@ pub fn part_one(input: &str) -> Option<u64> {
 
 pub fn part_two(input: &str) -> Option<u64> {
     let input = input.lines().map(|l| l.chars().collect_vec()).collect_vec();
-    let input = rotate(input);
+    let input = VecGrid::from_rows(input).transpose();
+    let input = input.rows().collect_vec();
 
     let result = input
         .split(|v| v.iter().all(|c| *c == ' '))
@@ -1,11 +1,11 @@
 advent_of_code::solution!(7);
-use std::collections::HashMap;
 
 #[allow(unused_imports)]
 use advent_of_code::prelude::*;
 use advent_of_code::{
     components::Point,
-    grid::{self, Grid, char_grid::CharGrid},
+    grid::{Grid, char_grid::CharGrid},
+    memoize,
 };
 
 fn parse_input(input: &str) -> advent_of_code::grid::char_grid::CharGrid {
@@ -55,21 +55,19 @@ fn find_next(grid: &CharGrid, mut pos: Point) -> Option<Point> {
     }
 }
 
-fn ray(grid: &CharGrid, pos: Point, cache: &mut HashMap<Point, u64>) -> u64 {
-    if let Some(value) = cache.get(&pos) {
-        return *value;
-    }
-
-    let left = find_next(grid, pos + Point::LEFT)
-        .map(|p| ray(grid, p, cache))
-        .unwrap_or(1);
+memoize! {
+    key: pos => Point;
+    fn ray(grid: &CharGrid, pos: Point) -> u64 {
+        let left = find_next(grid, pos + Point::LEFT)
+            .map(|p| ray(grid, p))
+            .unwrap_or(1);
 
-    let right = find_next(grid, pos + Point::RIGHT)
-        .map(|p| ray(grid, p, cache))
-        .unwrap_or(1);
+        let right = find_next(grid, pos + Point::RIGHT)
+            .map(|p| ray(grid, p))
+            .unwrap_or(1);
 
-    cache.insert(pos, left + right);
-    left + right
+        left + right
+    }
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
@@ -77,8 +75,8 @@ pub fn part_two(input: &str) -> Option<u64> {
 
     let start = grid.entries().find(|v| v.1 == 'S').unwrap().0;
 
-    let mut cache = HashMap::new();
-    Some(ray(&grid, start, &mut cache))
+    ray::clear();
+    Some(ray(&grid, start))
 }
 
 #[cfg(test)]
@@ -1,7 +1,6 @@
 advent_of_code::solution!(8);
 
-use std::collections::{BTreeMap, HashMap, btree_map::Entry};
-
+use advent_of_code::components::{Point3, union_find::UnionFind};
 #[allow(unused_imports)]
 use advent_of_code::prelude::*;
 
@@ -10,149 +9,43 @@ const CONNECTIONS: usize = 1000;
 #[cfg(test)]
 const CONNECTIONS: usize = 10;
 
-fn parse_input(input: &str) -> Vec<[i64; 3]> {
+fn parse_input(input: &str) -> Vec<Point3> {
     input
         .lines()
-        .map(|line| {
-            let [x, y, z] = line
-                .split(",")
-                .map(|s| s.parse::<i64>().unwrap())
-                .collect_array()
-                .unwrap();
-
-            [x, y, z]
-        })
+        .map(|line| Point3::parse_seperated(line, ",").unwrap())
+        .collect_vec()
+}
+
+/// All pairs of junction boxes, closest first
+fn pairs_by_distance(input: &[Point3]) -> Vec<(usize, usize)> {
+    (0..input.len())
+        .tuple_combinations()
+        .sorted_by_key(|(left, right)| input[*left].squared_distance(&input[*right]))
         .collect_vec()
 }
 
 pub fn part_one(input: &str) -> Option<u64> {
     let input = parse_input(input);
 
-    let mut distances = BTreeMap::new();
-    let mut idx = 0;
-    let mut circuits: HashMap<&[i64; 3], u64> = HashMap::new();
-
-    for (left, right) in input.iter().tuple_combinations() {
-        let distance: i64 = left
-            .iter()
-            .zip(right.iter())
-            .map(|(p1, p2)| (p2 - p1).pow(2))
-            .sum::<i64>()
-            .isqrt();
-
-        match distances.entry(distance) {
-            Entry::Vacant(entry) => {
-                entry.insert(vec![(left, right)]);
-            }
-            Entry::Occupied(mut entry) => {
-                entry.get_mut().push((left, right));
-            }
-        }
-    }
+    let mut circuits = UnionFind::new(input.len());
 
-    for (left, right) in distances.values().flatten().take(CONNECTIONS) {
-        let left_circuit = circuits.get(left);
-        let right_circuit = circuits.get(right);
-
-        match (left_circuit, right_circuit) {
-            (Some(left_circuit), Some(right_circuit)) if left_circuit == right_circuit => {}
-            (Some(left_circuit), Some(right_circuit)) => {
-                let left_circuit = *left_circuit;
-                let right_circuit = *right_circuit;
-                circuits.iter_mut().for_each(|m| {
-                    if *m.1 == right_circuit {
-                        *m.1 = left_circuit;
-                    }
-                });
-            }
-            (Some(left_circuit), None) => {
-                circuits.insert(right, *left_circuit);
-            }
-            (None, Some(right_circuit)) => {
-                circuits.insert(left, *right_circuit);
-            }
-            (None, None) => {
-                circuits.insert(left, idx);
-                circuits.insert(right, idx);
-                idx += 1;
-            }
-        }
+    for (left, right) in pairs_by_distance(&input).into_iter().take(CONNECTIONS) {
+        circuits.union(left, right);
     }
 
-    let mut counts = circuits
-        .iter()
-        .counts_by(|v| v.1)
-        .into_iter()
-        .map(|v| v.1)
-        .collect_vec();
-
-    counts.sort();
-
-    Some(
-        counts
-            .into_iter()
-            .rev()
-            .take(3)
-            .reduce(|a, b| a * b)
-            .unwrap() as u64,
-    )
+    Some(circuits.sizes().into_iter().take(3).product::<usize>() as u64)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
     let input = parse_input(input);
 
-    let mut distances = BTreeMap::new();
-    let mut idx = 0;
-    let mut circuits: HashMap<&[i64; 3], u64> = HashMap::new();
-
-    for (left, right) in input.iter().tuple_combinations() {
-        let distance: i64 = left
-            .iter()
-            .zip(right.iter())
-            .map(|(p1, p2)| (p2 - p1).pow(2))
-            .sum::<i64>()
-            .isqrt();
-
-        match distances.entry(distance) {
-            Entry::Vacant(entry) => {
-                entry.insert(vec![(left, right)]);
-            }
-            Entry::Occupied(mut entry) => {
-                entry.get_mut().push((left, right));
-            }
-        }
-    }
+    let mut circuits = UnionFind::new(input.len());
 
-    for (left, right) in distances.values().flatten() {
-        let left_circuit = circuits.get(left);
-        let right_circuit = circuits.get(right);
-
-        match (left_circuit, right_circuit) {
-            (Some(left_circuit), Some(right_circuit)) if left_circuit == right_circuit => {}
-            (Some(left_circuit), Some(right_circuit)) => {
-                let left_circuit = *left_circuit;
-                let right_circuit = *right_circuit;
-                circuits.iter_mut().for_each(|m| {
-                    if *m.1 == right_circuit {
-                        *m.1 = left_circuit;
-                    }
-                });
-            }
-            (Some(left_circuit), None) => {
-                circuits.insert(right, *left_circuit);
-            }
-            (None, Some(right_circuit)) => {
-                circuits.insert(left, *right_circuit);
-            }
-            (None, None) => {
-                circuits.insert(left, idx);
-                circuits.insert(right, idx);
-                idx += 1;
-            }
-        }
+    for (left, right) in pairs_by_distance(&input) {
+        circuits.union(left, right);
 
-        if circuits.len() == input.len() && circuits.values().unique().count() == 1 {
-            return Some((left[0] * right[0]) as u64);
+        if circuits.count() == 1 {
+            return Some((input[left].x * input[right].x) as u64);
         }
     }
 
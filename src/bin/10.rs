@@ -8,30 +8,24 @@ use advent_of_code::prelude::*;
 
 #[allow(clippy::type_complexity)]
 fn parse_input(input: &str) -> Vec<(Vec<bool>, Vec<Vec<usize>>, Vec<usize>)> {
-    input
-        .lines()
-        .map(|line| {
-            let (light_diagram, line) = line.split_once(" ").unwrap();
-            let light_diagram = light_diagram[1..light_diagram.len() - 1]
-                .chars()
-                .map(|c| c == '#')
-                .collect_vec();
-
-            let mut buttons = line
-                .split(" ")
-                .map(|button| {
-                    button[1..button.len() - 1]
-                        .split(",")
-                        .map(|b| b.parse::<usize>().unwrap())
-                        .collect_vec()
-                })
-                .collect_vec();
-
-            let joltage = buttons.pop().unwrap();
+    let numbers = || parse::separated(parse::unsigned(), ",");
+    let light_diagram = parse::bracketed("[", parse::many(parse::one_of(".#")), "]");
+    let buttons = parse::many(parse::preceded(
+        parse::spaces(),
+        parse::bracketed("(", numbers(), ")"),
+    ));
+    let joltage = parse::preceded(parse::spaces(), parse::bracketed("{", numbers(), "}"));
+
+    let line = parse::map(
+        parse::pair(light_diagram, parse::pair(buttons, joltage)),
+        |(light_diagram, (buttons, joltage))| {
+            let light_diagram = light_diagram.into_iter().map(|c| c == '#').collect_vec();
 
             (light_diagram, buttons, joltage)
-        })
-        .collect_vec()
+        },
+    );
+
+    parse::parse_lines(input, line).unwrap()
 }
 
 fn apply_click(state: &[bool], button: &[usize]) -> Vec<bool> {
@@ -101,17 +95,16 @@ fn buttons_to_matrix(buttons: Vec<Vec<usize>>, m: usize) -> Matrix {
 pub fn part_two(input: &str) -> Option<u64> {
     let input = parse_input(input);
 
-    let result: usize = input
-        .into_iter()
-        .enumerate()
-        .map(|(idx, (_, buttons, joltage))| {
-            let matrix = Matrix::from(joltage.iter().map(|v| *v as isize).collect_vec());
-            let mut buttons = buttons_to_matrix(buttons, joltage.len());
-            buttons.append(matrix);
-            println!("idx: {idx}\n{}", buttons);
-
-            buttons.row_echelon();
-            dbg!(buttons.solve().unwrap())
+    let result: isize = input
+        .into_par_iter()
+        .map(|(_, buttons, joltage)| {
+            let objective = vec![1; buttons.len()];
+            let mut matrix = buttons_to_matrix(buttons, joltage.len());
+            matrix.append(Matrix::from(
+                joltage.iter().map(|v| *v as isize).collect_vec(),
+            ));
+
+            matrix.minimize(&objective).unwrap().0
         })
         .sum();
 
@@ -139,7 +132,6 @@ mod tests {
         let result = part_two(&advent_of_code::template::read_file_part(
             "examples", DAY, 2,
         ));
-        dbg!(result);
         assert!(result.is_some());
     }
 }
@@ -1,59 +1,84 @@
 advent_of_code::solution!(12);
+use advent_of_code::components::shapes::{Shape, pack};
 #[allow(unused_imports)]
 use advent_of_code::prelude::*;
 
-fn parse_input(input: &str) -> (std::vec::Vec<u64>, std::vec::Vec<(u64, std::vec::Vec<u64>)>) {
-    let blocks = input
-        .split("\n\n")
-        .take_while(|s| !s.contains("x"))
-        .map(|block| block.chars().filter(|c| *c == '#').count() as u64)
+struct Region {
+    width: usize,
+    height: usize,
+    counts: Vec<usize>,
+}
+
+fn parse_input(input: &str) -> (Vec<Shape>, Vec<Region>) {
+    let blocks = input.trim().split("\n\n").collect_vec();
+    let (regions, shapes) = blocks.split_last().unwrap();
+
+    let shapes = shapes
+        .iter()
+        .map(|block| {
+            let (_, shape) = block.split_once(":").unwrap();
+            Shape::parse(shape).unwrap()
+        })
         .collect_vec();
-    let grids = input
-        .split("\n\n")
-        .last()
-        .unwrap()
+
+    let regions = regions
         .lines()
         .map(|line| {
-            let (grid, blocks) = line.split_once(":").unwrap();
-
-            let (width, height) = grid.split_once("x").unwrap();
-            let blocks = blocks
-                .trim()
-                .split(" ")
-                .map(|b| b.parse::<u64>().unwrap())
-                .collect_vec();
-
-            (
-                width.parse::<u64>().unwrap() * height.parse::<u64>().unwrap(),
-                blocks,
-            )
+            let (size, counts) = line.split_once(":").unwrap();
+            let (width, height) = size.split_once("x").unwrap();
+
+            Region {
+                width: width.parse().unwrap(),
+                height: height.parse().unwrap(),
+                counts: counts
+                    .split_whitespace()
+                    .map(|c| c.parse().unwrap())
+                    .collect_vec(),
+            }
         })
         .collect_vec();
 
-    (blocks, grids)
+    (shapes, regions)
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let (blocks, grids) = parse_input(input);
+fn fits(shapes: &[Shape], region: &Region) -> bool {
+    let required: usize = shapes
+        .iter()
+        .zip(&region.counts)
+        .map(|(shape, count)| shape.area() * count)
+        .sum();
 
-    let result = grids
+    if required > region.width * region.height {
+        return false;
+    }
+
+    // When every shape gets its own square box there is no need to search for a packing
+    let size = shapes
         .iter()
-        .filter(|(area, block_counts)| {
-            let min_area: u64 = block_counts
-                .iter()
-                .enumerate()
-                .map(|(idx, count)| blocks[idx] * count)
-                .sum();
-
-            min_area <= *area
-        })
+        .map(|s| s.width().max(s.height()))
+        .max()
+        .unwrap();
+    let boxes = (region.width / size) * (region.height / size);
+    if boxes >= region.counts.iter().sum() {
+        return true;
+    }
+
+    pack(shapes, &region.counts, region.width, region.height).is_some()
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let (shapes, regions) = parse_input(input);
+
+    let result = regions
+        .par_iter()
+        .filter(|region| fits(&shapes, region))
         .count();
 
     Some(result as u64)
 }
 
 pub fn part_two(input: &str) -> Option<u64> {
-    let input = parse_input(input);
+    let _input = parse_input(input);
 
     None
 }
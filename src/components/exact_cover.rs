@@ -0,0 +1,399 @@
+use itertools::Itertools;
+
+/// An exact cover problem, solved with Knuth's Algorithm X using dancing links.
+///
+/// Every row covers a set of columns. A solution is a set of rows that covers every primary column
+/// exactly once and every secondary column at most once.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::exact_cover::ExactCover;
+///
+/// let mut problem = ExactCover::new(4, 0);
+/// problem.add_row(&[0, 1]);
+/// problem.add_row(&[2, 3]);
+/// problem.add_row(&[1, 2]);
+/// problem.add_row(&[0, 3]);
+///
+/// assert_eq!(problem.count(), 2);
+/// assert_eq!(problem.first_solution(), Some(vec![0, 1]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExactCover {
+    primary: usize,
+    secondary: usize,
+    rows: Vec<Vec<usize>>,
+}
+
+impl ExactCover {
+    /// Creates a problem with columns 0..primary that must be covered exactly once, followed by
+    /// `secondary` columns that may be covered at most once
+    pub fn new(primary: usize, secondary: usize) -> ExactCover {
+        ExactCover {
+            primary,
+            secondary,
+            rows: vec![],
+        }
+    }
+
+    /// Adds a row covering the given columns, returning its index
+    /// Panics if the row is empty, a column is out of range or listed twice
+    pub fn add_row(&mut self, columns: &[usize]) -> usize {
+        assert!(!columns.is_empty(), "a row needs at least one column");
+
+        let count = self.primary + self.secondary;
+        if let Some(column) = columns.iter().find(|c| **c >= count) {
+            panic!("column {column} out of range, only {count} columns");
+        }
+
+        if let Some(column) = columns.iter().duplicates().next() {
+            panic!("column {column} listed twice in one row");
+        }
+
+        self.rows.push(columns.to_vec());
+        self.rows.len() - 1
+    }
+
+    /// Iterates every solution, as the indices of the chosen rows in ascending order
+    pub fn solutions(&self) -> Solutions {
+        Solutions::new(self)
+    }
+
+    /// Stops searching at the first solution found
+    pub fn first_solution(&self) -> Option<Vec<usize>> {
+        self.solutions().next()
+    }
+
+    /// Number of solutions
+    pub fn count(&self) -> usize {
+        self.solutions().count()
+    }
+}
+
+/// Iterator over the solutions of an ExactCover, searching lazily between solutions.
+///
+/// Nodes live in flat vectors linked by index. Node 0 is the root, followed by one header per
+/// column and then the nodes of every row.
+pub struct Solutions {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    column: Vec<usize>,
+    row: Vec<usize>,
+    /// Number of rows left in every column, indexed by header node
+    size: Vec<usize>,
+    /// The row node chosen at every level of the search
+    stack: Vec<usize>,
+    started: bool,
+    done: bool,
+}
+
+const ROOT: usize = 0;
+
+impl Solutions {
+    fn new(problem: &ExactCover) -> Solutions {
+        let columns = problem.primary + problem.secondary;
+        let headers = columns + 1;
+
+        let mut links = Solutions {
+            left: (0..headers).map(|i| i.saturating_sub(1)).collect(),
+            right: (0..headers).map(|i| i + 1).collect(),
+            up: (0..headers).collect(),
+            down: (0..headers).collect(),
+            column: (0..headers).collect(),
+            row: vec![usize::MAX; headers],
+            size: vec![0; headers],
+            stack: vec![],
+            started: false,
+            done: false,
+        };
+
+        // Only primary columns are part of the header list, secondary columns link to themselves
+        links.left[ROOT] = problem.primary;
+        links.right[problem.primary] = ROOT;
+        for header in problem.primary + 1..headers {
+            links.left[header] = header;
+            links.right[header] = header;
+        }
+
+        for (index, columns) in problem.rows.iter().enumerate() {
+            let first = links.column.len();
+
+            for (i, column) in columns.iter().enumerate() {
+                let header = column + 1;
+                let node = links.column.len();
+
+                links.column.push(header);
+                links.row.push(index);
+                links.left.push(if i == 0 { node } else { node - 1 });
+                links.right.push(first);
+                links.right[links.left[node]] = node;
+                links.left[first] = node;
+
+                links.down.push(header);
+                links.up.push(links.up[header]);
+                links.down[links.up[header]] = node;
+                links.up[header] = node;
+                links.size[header] += 1;
+            }
+        }
+
+        links
+    }
+
+    fn cover(&mut self, header: usize) {
+        self.right[self.left[header]] = self.right[header];
+        self.left[self.right[header]] = self.left[header];
+
+        let mut i = self.down[header];
+        while i != header {
+            let mut j = self.right[i];
+            while j != i {
+                self.down[self.up[j]] = self.down[j];
+                self.up[self.down[j]] = self.up[j];
+                self.size[self.column[j]] -= 1;
+                j = self.right[j];
+            }
+            i = self.down[i];
+        }
+    }
+
+    fn uncover(&mut self, header: usize) {
+        let mut i = self.up[header];
+        while i != header {
+            let mut j = self.left[i];
+            while j != i {
+                self.size[self.column[j]] += 1;
+                self.down[self.up[j]] = j;
+                self.up[self.down[j]] = j;
+                j = self.left[j];
+            }
+            i = self.up[i];
+        }
+
+        self.right[self.left[header]] = header;
+        self.left[self.right[header]] = header;
+    }
+
+    /// Covers the other columns of the row a node belongs to
+    fn select(&mut self, node: usize) {
+        let mut j = self.right[node];
+        while j != node {
+            self.cover(self.column[j]);
+            j = self.right[j];
+        }
+    }
+
+    /// Undoes `select`, in reverse order
+    fn deselect(&mut self, node: usize) {
+        let mut j = self.left[node];
+        while j != node {
+            self.uncover(self.column[j]);
+            j = self.left[j];
+        }
+    }
+
+    /// Keeps choosing the column with the fewest rows and selecting its first row. Returns true when
+    /// every primary column is covered, false when a column without rows is found
+    fn descend(&mut self) -> bool {
+        loop {
+            if self.right[ROOT] == ROOT {
+                return true;
+            }
+
+            let mut header = self.right[ROOT];
+            let mut i = self.right[header];
+            while i != ROOT {
+                if self.size[i] < self.size[header] {
+                    header = i;
+                }
+                i = self.right[i];
+            }
+
+            self.cover(header);
+
+            let node = self.down[header];
+            if node == header {
+                self.uncover(header);
+                return false;
+            }
+
+            self.select(node);
+            self.stack.push(node);
+        }
+    }
+
+    fn solution(&self) -> Vec<usize> {
+        let mut rows = self
+            .stack
+            .iter()
+            .map(|node| self.row[*node])
+            .collect::<Vec<_>>();
+        rows.sort_unstable();
+
+        rows
+    }
+}
+
+impl Iterator for Solutions {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if !self.started {
+            self.started = true;
+
+            if self.descend() {
+                return Some(self.solution());
+            }
+        }
+
+        // Backtrack from the last solution or dead end by moving on to the next row of the
+        // deepest column that has one left
+        loop {
+            let Some(node) = self.stack.pop() else {
+                self.done = true;
+                return None;
+            };
+
+            self.deselect(node);
+
+            let header = self.column[node];
+            let next = self.down[node];
+            if next == header {
+                self.uncover(header);
+                continue;
+            }
+
+            self.select(next);
+            self.stack.push(next);
+
+            if self.descend() {
+                return Some(self.solution());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use rstest::rstest;
+
+    use super::*;
+
+    /// Every way to place a domino on a width by height board, covering one column per cell
+    fn dominoes(width: usize, height: usize) -> ExactCover {
+        let mut problem = ExactCover::new(width * height, 0);
+
+        for y in 0..height {
+            for x in 0..width {
+                let cell = y * width + x;
+                if x + 1 < width {
+                    problem.add_row(&[cell, cell + 1]);
+                }
+                if y + 1 < height {
+                    problem.add_row(&[cell, cell + width]);
+                }
+            }
+        }
+
+        problem
+    }
+
+    /// N queens, with ranks and files as primary and the diagonals as secondary columns
+    fn queens(n: usize) -> ExactCover {
+        let mut problem = ExactCover::new(2 * n, 2 * (2 * n - 1));
+
+        for rank in 0..n {
+            for file in 0..n {
+                let diagonal = 2 * n + rank + file;
+                let anti_diagonal = 2 * n + (2 * n - 1) + (n - 1 + rank - file);
+
+                problem.add_row(&[rank, n + file, diagonal, anti_diagonal]);
+            }
+        }
+
+        problem
+    }
+
+    #[rstest]
+    #[case(2, 1, 1)]
+    #[case(2, 10, 89)]
+    #[case(3, 4, 11)]
+    #[case(4, 4, 36)]
+    #[case(3, 3, 0)]
+    #[case(6, 6, 6728)]
+    fn counts_domino_tilings(#[case] width: usize, #[case] height: usize, #[case] expected: usize) {
+        assert_eq!(dominoes(width, height).count(), expected);
+    }
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(4, 2)]
+    #[case(6, 4)]
+    #[case(8, 92)]
+    fn counts_queens_with_secondary_columns(#[case] n: usize, #[case] expected: usize) {
+        assert_eq!(queens(n).count(), expected);
+    }
+
+    #[rstest]
+    fn solutions_are_exact_covers() {
+        let problem = dominoes(4, 3);
+
+        let solutions = problem.solutions().collect_vec();
+
+        assert_eq!(solutions.len(), 11);
+        assert_eq!(solutions.iter().unique().count(), 11);
+
+        for solution in solutions {
+            let cells = solution
+                .iter()
+                .flat_map(|row| problem.rows[*row].iter().copied())
+                .sorted()
+                .collect_vec();
+            assert_eq!(cells, (0..12).collect_vec());
+        }
+    }
+
+    #[rstest]
+    fn first_solution_stops_early() {
+        let problem = dominoes(8, 8);
+
+        let solution = problem.first_solution().unwrap();
+        assert_eq!(solution.len(), 32);
+
+        let mut solutions = problem.solutions();
+        assert!(solutions.next().is_some());
+        assert!(solutions.next().is_some());
+    }
+
+    #[rstest]
+    fn handles_trivial_problems() {
+        assert_eq!(
+            ExactCover::new(0, 3).solutions().collect_vec(),
+            vec![vec![]]
+        );
+        assert_eq!(ExactCover::new(2, 0).count(), 0);
+
+        let mut problem = ExactCover::new(1, 1);
+        problem.add_row(&[1]);
+        assert_eq!(problem.first_solution(), None);
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn add_row_should_panic_out_of_range() {
+        ExactCover::new(2, 1).add_row(&[0, 3]);
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn add_row_should_panic_on_duplicate_columns() {
+        ExactCover::new(2, 1).add_row(&[0, 2, 0]);
+    }
+}
@@ -0,0 +1,459 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Display, Write},
+    hash::Hash,
+};
+
+use itertools::Itertools;
+
+use crate::grid::Grid;
+
+use super::{
+    Point,
+    graph::{Adjacency, nodes},
+};
+
+const NODE_HEIGHT: usize = 30;
+const LAYER_GAP: usize = 60;
+const NODE_GAP: usize = 20;
+const MARGIN: usize = 20;
+/// Rough width of a character in the 14px sans-serif font used for labels
+const CHAR_WIDTH: usize = 8;
+
+/// Every node with its label, sorted by label so exports are stable between runs
+fn labelled<N>(graph: &Adjacency<N>) -> Vec<(N, String)>
+where
+    N: Copy + Eq + Hash + Display,
+{
+    nodes(graph)
+        .into_iter()
+        .map(|n| (n, n.to_string()))
+        .sorted_by(|a, b| a.1.cmp(&b.1))
+        .collect()
+}
+
+fn escape_xml(label: &str) -> String {
+    label
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Writes the graph in the DOT language, to be rendered by Graphviz or any online viewer
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use advent_of_code::components::export;
+///
+/// let graph = HashMap::from([("a", vec!["b", "c"])]);
+///
+/// assert_eq!(
+///     export::dot(&graph),
+///     "digraph {\n    \"a\";\n    \"b\";\n    \"c\";\n    \"a\" -> \"b\";\n    \"a\" -> \"c\";\n}\n"
+/// );
+/// ```
+pub fn dot<N>(graph: &Adjacency<N>) -> String
+where
+    N: Copy + Eq + Hash + Display,
+{
+    let nodes = labelled(graph);
+    let labels = nodes.iter().cloned().collect::<HashMap<_, _>>();
+    let quote = |n: &N| {
+        format!(
+            "\"{}\"",
+            labels[n].replace('\\', "\\\\").replace('"', "\\\"")
+        )
+    };
+
+    let mut s = String::from("digraph {\n");
+    for (node, _) in &nodes {
+        writeln!(s, "    {};", quote(node)).unwrap();
+    }
+    for (node, _) in &nodes {
+        for to in graph.get(node).into_iter().flatten() {
+            writeln!(s, "    {} -> {};", quote(node), quote(to)).unwrap();
+        }
+    }
+    s += "}\n";
+
+    s
+}
+
+/// Writes the graph as a Mermaid flowchart, which renders in markdown on GitHub
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use advent_of_code::components::export;
+///
+/// let graph = HashMap::from([("a", vec!["b"])]);
+///
+/// assert_eq!(
+///     export::mermaid(&graph),
+///     "flowchart TD\n    n0[\"a\"]\n    n1[\"b\"]\n    n0 --> n1\n"
+/// );
+/// ```
+pub fn mermaid<N>(graph: &Adjacency<N>) -> String
+where
+    N: Copy + Eq + Hash + Display,
+{
+    let nodes = labelled(graph);
+    let ids = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, (n, _))| (*n, format!("n{i}")))
+        .collect::<HashMap<_, _>>();
+
+    let mut s = String::from("flowchart TD\n");
+    for (node, label) in &nodes {
+        writeln!(s, "    {}[\"{}\"]", ids[node], label.replace('"', "#quot;")).unwrap();
+    }
+    for (node, _) in &nodes {
+        for to in graph.get(node).into_iter().flatten() {
+            writeln!(s, "    {} --> {}", ids[node], ids[to]).unwrap();
+        }
+    }
+
+    s
+}
+
+/// Assigns every node to a layer, so that edges point to a later layer. Edges closing a cycle are
+/// ignored, the nodes are visited depth first in label order to decide which edges those are
+fn layers<N>(graph: &Adjacency<N>, nodes: &[(N, String)]) -> Vec<Vec<N>>
+where
+    N: Copy + Eq + Hash,
+{
+    fn visit<N>(graph: &Adjacency<N>, node: N, seen: &mut HashSet<N>, order: &mut Vec<N>)
+    where
+        N: Copy + Eq + Hash,
+    {
+        if !seen.insert(node) {
+            return;
+        }
+
+        for next in graph.get(&node).into_iter().flatten() {
+            visit(graph, *next, seen, order);
+        }
+        order.push(node);
+    }
+
+    let mut seen = HashSet::new();
+    let mut order = vec![];
+    for (node, _) in nodes {
+        visit(graph, *node, &mut seen, &mut order);
+    }
+    order.reverse();
+
+    // Longest path layering over the reverse post order, which is a topological order once the
+    // edges closing a cycle are dropped
+    let position = order
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (*n, i))
+        .collect::<HashMap<_, _>>();
+    let mut depth = HashMap::new();
+    for node in &order {
+        let d = *depth.entry(*node).or_insert(0);
+
+        for next in graph.get(node).into_iter().flatten() {
+            if position[next] > position[node] {
+                let entry = depth.entry(*next).or_insert(0);
+                *entry = (*entry).max(d + 1);
+            }
+        }
+    }
+
+    let mut layers = vec![vec![]; depth.values().max().map_or(0, |d| d + 1)];
+    for (node, _) in nodes {
+        layers[depth[node]].push(*node);
+    }
+
+    layers
+}
+
+/// Draws the graph as a standalone SVG, placing nodes in layers from top to bottom so every edge
+/// points down, except for edges closing a cycle
+pub fn svg<N>(graph: &Adjacency<N>) -> String
+where
+    N: Copy + Eq + Hash + Display,
+{
+    let nodes = labelled(graph);
+    let labels = nodes.iter().cloned().collect::<HashMap<_, _>>();
+    let mut layers = layers(graph, &nodes);
+    let predecessors = super::graph::reverse(graph);
+
+    // Orders every layer by the average position of its predecessors above, which keeps most
+    // edges short and avoids a lot of crossings
+    let mut column = HashMap::new();
+    for layer in layers.iter_mut() {
+        let barycenter = |n: &N| {
+            let placed = predecessors
+                .get(n)
+                .into_iter()
+                .flatten()
+                .filter_map(|p| column.get(p))
+                .collect_vec();
+
+            if placed.is_empty() {
+                f64::MAX
+            } else {
+                placed.iter().copied().sum::<f64>() / placed.len() as f64
+            }
+        };
+
+        layer.sort_by(|a, b| barycenter(a).total_cmp(&barycenter(b)));
+        for (i, node) in layer.iter().enumerate() {
+            column.insert(*node, i as f64);
+        }
+    }
+
+    let widths = labels
+        .iter()
+        .map(|(n, label)| (*n, label.chars().count() * CHAR_WIDTH + 2 * NODE_GAP))
+        .collect::<HashMap<_, _>>();
+    let layer_width = |layer: &Vec<N>| {
+        layer.iter().map(|n| widths[n]).sum::<usize>() + NODE_GAP * layer.len().saturating_sub(1)
+    };
+    let width = layers.iter().map(layer_width).max().unwrap_or(0) + 2 * MARGIN;
+    let height = (layers.len() * (NODE_HEIGHT + LAYER_GAP)).saturating_sub(LAYER_GAP) + 2 * MARGIN;
+
+    // Center of every node, with layers centered horizontally
+    let mut centers = HashMap::new();
+    for (i, layer) in layers.iter().enumerate() {
+        let mut x = (width - layer_width(layer)) / 2;
+        let y = MARGIN + i * (NODE_HEIGHT + LAYER_GAP) + NODE_HEIGHT / 2;
+
+        for node in layer {
+            centers.insert(*node, (x + widths[node] / 2, y));
+            x += widths[node] + NODE_GAP;
+        }
+    }
+
+    let mut s = String::new();
+    writeln!(
+        s,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="sans-serif" font-size="14">"#
+    )
+    .unwrap();
+    s += r#"<defs><marker id="arrow" viewBox="0 0 10 10" refX="10" refY="5" markerWidth="6" markerHeight="6" orient="auto"><path d="M0,0 L10,5 L0,10 z"/></marker></defs>"#;
+    s += "\n";
+
+    for (node, _) in &nodes {
+        let (x1, y1) = centers[node];
+        for to in graph.get(node).into_iter().flatten() {
+            let (x2, y2) = centers[to];
+            let (y1, y2) = if y2 > y1 {
+                (y1 + NODE_HEIGHT / 2, y2 - NODE_HEIGHT / 2)
+            } else {
+                (y1 - NODE_HEIGHT / 2, y2 + NODE_HEIGHT / 2)
+            };
+
+            writeln!(
+                s,
+                r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}" stroke="black" marker-end="url(#arrow)"/>"#
+            )
+            .unwrap();
+        }
+    }
+
+    for (node, label) in &nodes {
+        let (x, y) = centers[node];
+        let w = widths[node];
+
+        writeln!(
+            s,
+            r#"<rect x="{}" y="{}" width="{w}" height="{NODE_HEIGHT}" rx="6" fill="white" stroke="black"/>"#,
+            x - w / 2,
+            y - NODE_HEIGHT / 2,
+        )
+        .unwrap();
+        writeln!(
+            s,
+            r#"<text x="{x}" y="{y}" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+            escape_xml(label)
+        )
+        .unwrap();
+    }
+    s += "</svg>\n";
+
+    s
+}
+
+/// Turns a grid into a graph over its points, with an edge to every neighbour in `directions`
+/// for which `connected` returns true. Feed the result to any of the exporters
+pub fn grid_graph<'a, G, F>(grid: &'a G, directions: &[Point], connected: F) -> Adjacency<Point>
+where
+    G: Grid<'a>,
+    F: Fn(&Point, G::ReturnItem, &Point, G::ReturnItem) -> bool,
+{
+    grid.keys()
+        .map(|from| {
+            let to = directions
+                .iter()
+                .map(|direction| from + *direction)
+                .filter(|to| match (grid.get(&from), grid.get(to)) {
+                    (Some(a), Some(b)) => connected(&from, a, to, b),
+                    _ => false,
+                })
+                .collect();
+
+            (from, to)
+        })
+        .collect()
+}
+
+/// Draws a grid as a standalone SVG with a square of `cell` pixels for every point. `fill`
+/// returns the color of a point, points for which it returns None are left empty
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::export;
+/// use advent_of_code::grid::char_grid::CharGrid;
+///
+/// let grid = CharGrid::new("#.\n.#");
+/// let svg = export::grid_svg(&grid, 10, |_, c| (c == Some('#')).then(|| "black".to_string()));
+///
+/// assert_eq!(svg.matches("<rect").count(), 2);
+/// ```
+pub fn grid_svg<'a, G, F>(grid: &'a G, cell: usize, fill: F) -> String
+where
+    G: Grid<'a>,
+    F: Fn(&Point, Option<G::ReturnItem>) -> Option<String>,
+{
+    let (lower, upper) = grid.bounds();
+    let width = (upper.x - lower.x + 1) as usize * cell;
+    let height = (upper.y - lower.y + 1) as usize * cell;
+
+    let mut s = String::new();
+    writeln!(
+        s,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" shape-rendering="crispEdges">"#
+    )
+    .unwrap();
+
+    for y in lower.y..=upper.y {
+        for x in lower.x..=upper.x {
+            let point = Point::new(x, y);
+            let Some(color) = fill(&point, grid.get(&point)) else {
+                continue;
+            };
+
+            writeln!(
+                s,
+                r#"<rect x="{}" y="{}" width="{cell}" height="{cell}" fill="{}"/>"#,
+                (x - lower.x) as usize * cell,
+                (y - lower.y) as usize * cell,
+                escape_xml(&color)
+            )
+            .unwrap();
+        }
+    }
+    s += "</svg>\n";
+
+    s
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use crate::grid::char_grid::CharGrid;
+
+    use super::*;
+
+    fn graph() -> Adjacency<&'static str> {
+        HashMap::from([
+            ("svr", vec!["aaa", "bbb"]),
+            ("aaa", vec!["fft"]),
+            ("bbb", vec!["fft", "out"]),
+            ("fft", vec!["out"]),
+        ])
+    }
+
+    #[rstest]
+    fn it_writes_stable_dot() {
+        let dot = dot(&graph());
+
+        assert!(dot.starts_with("digraph {\n    \"aaa\";\n"));
+        assert!(dot.contains("    \"bbb\" -> \"fft\";\n    \"bbb\" -> \"out\";\n"));
+        assert_eq!(dot.matches("->").count(), 6);
+        assert_eq!(dot, super::dot(&graph()));
+    }
+
+    #[rstest]
+    fn it_escapes_labels() {
+        let graph = HashMap::from([("say \"hi\"", vec!["<&>"])]);
+
+        assert!(dot(&graph).contains(r#""say \"hi\"" -> "<&>""#));
+        assert!(mermaid(&graph).contains("n1[\"say #quot;hi#quot;\"]"));
+        assert!(svg(&graph).contains(">&lt;&amp;&gt;</text>"));
+    }
+
+    #[rstest]
+    fn it_writes_mermaid() {
+        let mermaid = mermaid(&graph());
+
+        assert!(mermaid.starts_with("flowchart TD\n    n0[\"aaa\"]\n"));
+        assert!(mermaid.contains("    n4 --> n0\n    n4 --> n1\n"));
+        assert_eq!(mermaid.lines().count(), 1 + 5 + 6);
+    }
+
+    #[rstest]
+    fn it_layers_nodes_along_edges() {
+        let graph = graph();
+        let nodes = labelled(&graph);
+
+        let layers = layers(&graph, &nodes);
+
+        assert_eq!(
+            layers,
+            vec![vec!["svr"], vec!["aaa", "bbb"], vec!["fft"], vec!["out"]]
+        );
+    }
+
+    #[rstest]
+    fn it_layers_cycles() {
+        let graph = HashMap::from([("a", vec!["b"]), ("b", vec!["c"]), ("c", vec!["a"])]);
+        let nodes = labelled(&graph);
+
+        assert_eq!(
+            layers(&graph, &nodes),
+            vec![vec!["a"], vec!["b"], vec!["c"]]
+        );
+        assert_eq!(svg(&graph).matches("<line").count(), 3);
+    }
+
+    #[rstest]
+    fn it_draws_svg() {
+        let svg = svg(&graph());
+
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<rect").count(), 5);
+        assert_eq!(svg.matches("<line").count(), 6);
+        assert!(svg.contains(">svr</text>"));
+    }
+
+    #[rstest]
+    fn it_exports_grids() {
+        let grid = CharGrid::new("..#\n#..");
+
+        let graph = grid_graph(&grid, &Point::DIRECTIONS_4, |_, a, _, b| {
+            a == '.' && b == '.'
+        });
+
+        assert_eq!(graph.len(), 6);
+        assert_eq!(graph[&Point::new(1, 0)].len(), 2);
+        assert!(graph[&Point::new(2, 0)].is_empty());
+        assert!(dot(&graph).contains("\"0,0\" -> \"1,0\";"));
+
+        let svg = grid_svg(&grid, 5, |_, c| match c {
+            Some('#') => Some("black".to_string()),
+            _ => None,
+        });
+        assert!(svg.contains(r#"<rect x="10" y="0" width="5" height="5" fill="black"/>"#));
+        assert!(svg.contains(r#"<rect x="0" y="5" width="5" height="5" fill="black"/>"#));
+    }
+}
@@ -0,0 +1,340 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    hash::Hash,
+};
+
+/// Directed graphs stored as an adjacency map from every node to the nodes it points at, as most
+/// puzzle inputs parse into. Nodes that only appear as a target are sinks and need no entry.
+pub type Adjacency<N> = HashMap<N, Vec<N>>;
+
+/// Every node of the graph, including sinks without an entry of their own
+pub fn nodes<N>(graph: &Adjacency<N>) -> HashSet<N>
+where
+    N: Copy + Eq + Hash,
+{
+    graph
+        .iter()
+        .flat_map(|(from, to)| std::iter::once(from).chain(to))
+        .copied()
+        .collect()
+}
+
+/// The graph with every edge pointing the other way
+pub fn reverse<N>(graph: &Adjacency<N>) -> Adjacency<N>
+where
+    N: Copy + Eq + Hash,
+{
+    let mut reversed: Adjacency<N> = HashMap::new();
+    for (from, to) in graph {
+        for to in to {
+            reversed.entry(*to).or_default().push(*from);
+        }
+    }
+
+    reversed
+}
+
+/// Orders the nodes so every edge points forward, using Kahn's algorithm. Nodes that are ready
+/// at the same time come out smallest first, so the order is stable between runs. Returns None
+/// if the graph contains a cycle
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use advent_of_code::components::graph::topological_sort;
+///
+/// let graph = HashMap::from([("b", vec!["c"]), ("a", vec!["b", "c"])]);
+/// assert_eq!(topological_sort(&graph), Some(vec!["a", "b", "c"]));
+///
+/// let graph = HashMap::from([("a", vec!["b"]), ("b", vec!["a"])]);
+/// assert_eq!(topological_sort(&graph), None);
+/// ```
+pub fn topological_sort<N>(graph: &Adjacency<N>) -> Option<Vec<N>>
+where
+    N: Copy + Eq + Hash + Ord,
+{
+    let nodes = nodes(graph);
+    let mut incoming = nodes.iter().map(|n| (*n, 0)).collect::<HashMap<_, _>>();
+    for to in graph.values().flatten() {
+        *incoming.get_mut(to).unwrap() += 1;
+    }
+
+    let mut ready = incoming
+        .iter()
+        .filter(|(_, count)| **count == 0)
+        .map(|(node, _)| *node)
+        .collect::<BTreeSet<_>>();
+
+    let mut order = Vec::with_capacity(nodes.len());
+    while let Some(node) = ready.pop_first() {
+        order.push(node);
+
+        for next in graph.get(&node).into_iter().flatten() {
+            let count = incoming.get_mut(next).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                ready.insert(*next);
+            }
+        }
+    }
+
+    (order.len() == nodes.len()).then_some(order)
+}
+
+/// Finds a cycle in the graph, returning its nodes in the order they are visited. The last node
+/// points back at the first. Returns None if the graph is acyclic
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use advent_of_code::components::graph::find_cycle;
+///
+/// let graph = HashMap::from([("a", vec!["b"]), ("b", vec!["c"]), ("c", vec!["b"])]);
+/// let cycle = find_cycle(&graph).unwrap();
+///
+/// assert_eq!(cycle.len(), 2);
+/// assert!(cycle.contains(&"b") && cycle.contains(&"c"));
+/// ```
+pub fn find_cycle<N>(graph: &Adjacency<N>) -> Option<Vec<N>>
+where
+    N: Copy + Eq + Hash,
+{
+    fn visit<N>(
+        graph: &Adjacency<N>,
+        node: N,
+        path: &mut Vec<N>,
+        done: &mut HashSet<N>,
+    ) -> Option<Vec<N>>
+    where
+        N: Copy + Eq + Hash,
+    {
+        if let Some(start) = path.iter().position(|n| *n == node) {
+            return Some(path[start..].to_vec());
+        }
+
+        if done.contains(&node) {
+            return None;
+        }
+
+        path.push(node);
+        for next in graph.get(&node).into_iter().flatten() {
+            if let Some(cycle) = visit(graph, *next, path, done) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        done.insert(node);
+
+        None
+    }
+
+    let mut done = HashSet::new();
+
+    graph
+        .keys()
+        .find_map(|node| visit(graph, *node, &mut vec![], &mut done))
+}
+
+/// Counts the distinct paths from `from` to `to`, remembering the count of every node visited so
+/// every node is only expanded once. Paths end as soon as they reach `to`
+/// Panics if a cycle is reachable from `from`, as there would be infinitely many paths
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use advent_of_code::components::graph::path_count;
+///
+/// let graph = HashMap::from([
+///     ("a", vec!["b", "c"]),
+///     ("b", vec!["c", "d"]),
+///     ("c", vec!["d"]),
+/// ]);
+///
+/// assert_eq!(path_count(&graph, "a", "d"), 3);
+/// assert_eq!(path_count(&graph, "d", "a"), 0);
+/// ```
+pub fn path_count<N>(graph: &Adjacency<N>, from: N, to: N) -> u64
+where
+    N: Copy + Eq + Hash,
+{
+    fn count<N>(graph: &Adjacency<N>, node: N, to: N, memo: &mut HashMap<N, Option<u64>>) -> u64
+    where
+        N: Copy + Eq + Hash,
+    {
+        if node == to {
+            return 1;
+        }
+
+        match memo.get(&node) {
+            Some(Some(count)) => return *count,
+            Some(None) => panic!("graph contains a cycle"),
+            None => {}
+        }
+
+        // Marks the node as in progress, reaching it again before it is done means a cycle
+        memo.insert(node, None);
+
+        let total = graph
+            .get(&node)
+            .into_iter()
+            .flatten()
+            .map(|next| count(graph, *next, to, memo))
+            .sum();
+
+        memo.insert(node, Some(total));
+        total
+    }
+
+    count(graph, from, to, &mut HashMap::new())
+}
+
+/// Counts the paths from `from` to `to` that visit every waypoint. In an acyclic graph those
+/// paths pass the waypoints in topological order, so the count is the product of the paths
+/// between every consecutive pair
+/// Panics if the graph contains a cycle
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use advent_of_code::components::graph::path_count_through;
+///
+/// let graph = HashMap::from([
+///     ("a", vec!["b", "c"]),
+///     ("b", vec!["c", "d"]),
+///     ("c", vec!["d"]),
+/// ]);
+///
+/// assert_eq!(path_count_through(&graph, "a", "d", &["c"]), 2);
+/// assert_eq!(path_count_through(&graph, "a", "d", &["c", "b"]), 1);
+/// ```
+pub fn path_count_through<N>(graph: &Adjacency<N>, from: N, to: N, waypoints: &[N]) -> u64
+where
+    N: Copy + Eq + Hash + Ord,
+{
+    let order = topological_sort(graph).expect("graph contains a cycle");
+    let position = order
+        .iter()
+        .enumerate()
+        .map(|(i, node)| (*node, i))
+        .collect::<HashMap<_, _>>();
+
+    let mut stops = waypoints.to_vec();
+    stops.sort_by_key(|node| position.get(node));
+
+    std::iter::once(from)
+        .chain(stops)
+        .chain(std::iter::once(to))
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|pair| path_count(graph, pair[0], pair[1]))
+        .product()
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn parse(input: &str) -> Adjacency<&str> {
+        input
+            .lines()
+            .map(|line| {
+                let (from, to) = line.split_once(": ").unwrap();
+
+                (from, to.split(' ').collect_vec())
+            })
+            .collect()
+    }
+
+    fn servers() -> Adjacency<&'static str> {
+        parse(
+            "svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out",
+        )
+    }
+
+    #[rstest]
+    fn it_sorts_topologically() {
+        let graph = servers();
+
+        let order = topological_sort(&graph).unwrap();
+
+        assert_eq!(order.len(), nodes(&graph).len());
+        assert_eq!(order.first(), Some(&"svr"));
+        assert_eq!(order.last(), Some(&"out"));
+        for (from, to) in graph.iter() {
+            let index = order.iter().position(|n| n == from).unwrap();
+            assert!(to.iter().all(|to| order[index..].contains(to)));
+        }
+    }
+
+    #[rstest]
+    fn it_detects_cycles() {
+        let mut graph = servers();
+        assert_eq!(find_cycle(&graph), None);
+
+        graph.insert("out", vec!["ccc"]);
+
+        let cycle = find_cycle(&graph).unwrap();
+        assert_eq!(topological_sort(&graph), None);
+        for (from, to) in cycle.iter().circular_tuple_windows() {
+            assert!(graph[from].contains(to));
+        }
+    }
+
+    #[rstest]
+    #[case("svr", "out", 8)]
+    #[case("svr", "ccc", 2)]
+    #[case("ccc", "out", 4)]
+    #[case("out", "svr", 0)]
+    #[case("hub", "hub", 1)]
+    fn it_counts_paths(#[case] from: &str, #[case] to: &str, #[case] expected: u64) {
+        assert_eq!(path_count(&servers(), from, to), expected);
+    }
+
+    #[rstest]
+    #[case(&[], 8)]
+    #[case(&["dac"], 4)]
+    #[case(&["dac", "fft"], 2)]
+    #[case(&["fft", "dac"], 2)]
+    #[case(&["dac", "hub"], 0)]
+    fn it_counts_paths_through_waypoints(#[case] waypoints: &[&str], #[case] expected: u64) {
+        assert_eq!(
+            path_count_through(&servers(), "svr", "out", waypoints),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn it_reverses_edges() {
+        let graph = servers();
+        let reversed = reverse(&graph);
+
+        assert_eq!(
+            reversed["out"].iter().sorted().collect_vec(),
+            vec![&"ggg", &"hhh"]
+        );
+        assert_eq!(path_count(&reversed, "out", "svr"), 8);
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn path_count_should_panic_on_cycles() {
+        let graph = HashMap::from([(1, vec![2]), (2, vec![3]), (3, vec![1, 4])]);
+
+        path_count(&graph, 1, 4);
+    }
+}
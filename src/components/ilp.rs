@@ -0,0 +1,612 @@
+use itertools::Itertools;
+
+/// Tolerance used when comparing floating point values in the simplex tableau
+const EPSILON: f64 = 1e-9;
+
+/// Number of branch and bound nodes explored before giving up
+const DEFAULT_NODE_LIMIT: usize = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sense {
+    Minimize,
+    Maximize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    LessEq,
+    GreaterEq,
+    Equal,
+}
+
+/// Reasons a problem has no optimal solution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IlpError {
+    /// No assignment satisfies every constraint and bound
+    Infeasible,
+    /// The objective can be improved without limit
+    Unbounded,
+    /// Branch and bound gave up after exploring the node limit, which happens when integer
+    /// variables without a finite bound have no integer solution
+    NodeLimit,
+}
+
+/// Handle to a variable of a Problem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Variable(usize);
+
+#[derive(Debug, Clone)]
+struct Constraint {
+    coefficients: Vec<(usize, f64)>,
+    relation: Relation,
+    rhs: f64,
+}
+
+/// An optimal assignment of a Problem
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    pub objective: f64,
+    pub values: Vec<f64>,
+}
+
+impl Solution {
+    pub fn value(&self, variable: Variable) -> f64 {
+        self.values[variable.0]
+    }
+
+    /// The value of a variable rounded to the nearest integer, exact for integer variables
+    pub fn integer(&self, variable: Variable) -> isize {
+        self.values[variable.0].round() as isize
+    }
+}
+
+/// A mixed integer linear program, solved by branch and bound over a simplex relaxation.
+/// Variables default to a lower bound of 0 and no upper bound
+/// Branching only ends for integer variables with finite bounds, otherwise the search is cut off
+/// after a limited number of nodes
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::ilp::{Problem, Relation};
+///
+/// // Buttons (0,1) and (1) pressed to reach counters {3,5} in as few presses as possible
+/// let mut problem = Problem::minimize();
+/// let a = problem.add_integer(1.0);
+/// let b = problem.add_integer(1.0);
+///
+/// problem.add_constraint(&[(a, 1.0)], Relation::Equal, 3.0);
+/// problem.add_constraint(&[(a, 1.0), (b, 1.0)], Relation::Equal, 5.0);
+///
+/// let solution = problem.solve().unwrap();
+/// assert_eq!(solution.objective, 5.0);
+/// assert_eq!(solution.integer(b), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Problem {
+    sense: Sense,
+    costs: Vec<f64>,
+    integer: Vec<bool>,
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+    constraints: Vec<Constraint>,
+    node_limit: usize,
+}
+
+impl Problem {
+    pub fn new(sense: Sense) -> Problem {
+        Problem {
+            sense,
+            costs: vec![],
+            integer: vec![],
+            lower: vec![],
+            upper: vec![],
+            constraints: vec![],
+            node_limit: DEFAULT_NODE_LIMIT,
+        }
+    }
+
+    pub fn minimize() -> Problem {
+        Self::new(Sense::Minimize)
+    }
+
+    pub fn maximize() -> Problem {
+        Self::new(Sense::Maximize)
+    }
+
+    /// Adds a continuous variable with its coefficient in the objective
+    pub fn add_variable(&mut self, cost: f64) -> Variable {
+        self.costs.push(cost);
+        self.integer.push(false);
+        self.lower.push(0.0);
+        self.upper.push(f64::INFINITY);
+
+        Variable(self.costs.len() - 1)
+    }
+
+    /// Adds a variable that can only take integer values, with its coefficient in the objective
+    /// Without a finite upper bound, a problem with no integer solution may hit the node limit
+    pub fn add_integer(&mut self, cost: f64) -> Variable {
+        let variable = self.add_variable(cost);
+        self.integer[variable.0] = true;
+
+        variable
+    }
+
+    /// Limits a variable to lower..=upper, use f64::INFINITY for no upper bound
+    /// Panics if the lower bound is not finite
+    pub fn set_bounds(&mut self, variable: Variable, lower: f64, upper: f64) {
+        assert!(lower.is_finite(), "lower bound must be finite");
+
+        self.lower[variable.0] = lower;
+        self.upper[variable.0] = upper;
+    }
+
+    /// Limits how many nodes solve explores before returning IlpError::NodeLimit
+    pub fn set_node_limit(&mut self, limit: usize) {
+        self.node_limit = limit;
+    }
+
+    /// Adds the constraint sum(coefficient * variable) `relation` rhs
+    pub fn add_constraint(&mut self, terms: &[(Variable, f64)], relation: Relation, rhs: f64) {
+        self.constraints.push(Constraint {
+            coefficients: terms.iter().map(|(v, c)| (v.0, *c)).collect(),
+            relation,
+            rhs,
+        });
+    }
+
+    /// Finds an optimal solution using depth first branch and bound
+    /// Errors with IlpError::NodeLimit if the search explores more nodes than the limit
+    pub fn solve(&self) -> Result<Solution, IlpError> {
+        let mut best: Option<Solution> = None;
+        let mut stack = vec![(self.lower.clone(), self.upper.clone())];
+        let mut nodes = 0;
+
+        while let Some((lower, upper)) = stack.pop() {
+            nodes += 1;
+            if nodes > self.node_limit {
+                return Err(IlpError::NodeLimit);
+            }
+
+            let relaxed = match self.relax(&lower, &upper) {
+                Ok(relaxed) => relaxed,
+                Err(IlpError::Infeasible) => continue,
+                Err(e) => return Err(e),
+            };
+
+            if best
+                .as_ref()
+                .is_some_and(|b| !self.improves(relaxed.objective, b.objective))
+            {
+                continue;
+            }
+
+            let fractional = (0..self.costs.len()).find(|i| {
+                self.integer[*i] && (relaxed.values[*i] - relaxed.values[*i].round()).abs() > 1e-6
+            });
+
+            let Some(i) = fractional else {
+                best = Some(self.round(relaxed));
+                continue;
+            };
+
+            let value = relaxed.values[i];
+
+            let mut up = lower.clone();
+            up[i] = value.ceil();
+            stack.push((up, upper.clone()));
+
+            let mut down = upper;
+            down[i] = value.floor();
+            stack.push((lower, down));
+        }
+
+        best.ok_or(IlpError::Infeasible)
+    }
+
+    fn improves(&self, objective: f64, best: f64) -> bool {
+        match self.sense {
+            Sense::Minimize => objective < best - 1e-6,
+            Sense::Maximize => objective > best + 1e-6,
+        }
+    }
+
+    /// Snaps integer variables to exact integers and recalculates the objective
+    fn round(&self, mut solution: Solution) -> Solution {
+        for (value, integer) in solution.values.iter_mut().zip(&self.integer) {
+            if *integer {
+                *value = value.round();
+            }
+        }
+
+        solution.objective = self
+            .costs
+            .iter()
+            .zip(&solution.values)
+            .map(|(c, v)| c * v)
+            .sum();
+
+        solution
+    }
+
+    /// Solves the LP relaxation within the given bounds, by shifting every variable to start at 0
+    /// and turning the upper bounds into constraints
+    fn relax(&self, lower: &[f64], upper: &[f64]) -> Result<Solution, IlpError> {
+        let n = self.costs.len();
+        if (0..n).any(|i| lower[i] > upper[i] + EPSILON) {
+            return Err(IlpError::Infeasible);
+        }
+
+        let mut rows = self
+            .constraints
+            .iter()
+            .map(|constraint| {
+                let mut row = vec![0.0; n];
+                let mut rhs = constraint.rhs;
+
+                for (i, c) in &constraint.coefficients {
+                    row[*i] += c;
+                    rhs -= c * lower[*i];
+                }
+
+                (row, constraint.relation, rhs)
+            })
+            .collect_vec();
+
+        for i in (0..n).filter(|i| upper[*i].is_finite()) {
+            let mut row = vec![0.0; n];
+            row[i] = 1.0;
+            rows.push((row, Relation::LessEq, upper[i] - lower[i]));
+        }
+
+        let costs = match self.sense {
+            Sense::Minimize => self.costs.clone(),
+            Sense::Maximize => self.costs.iter().map(|c| -c).collect(),
+        };
+
+        let shifted = simplex(&costs, &rows)?;
+        let values = shifted.iter().zip(lower).map(|(v, l)| v + l).collect_vec();
+
+        Ok(Solution {
+            objective: self.costs.iter().zip(&values).map(|(c, v)| c * v).sum(),
+            values,
+        })
+    }
+}
+
+/// Dense simplex tableau, the last column holds the right hand side
+struct Tableau {
+    rows: Vec<Vec<f64>>,
+    basis: Vec<usize>,
+}
+
+impl Tableau {
+    fn rhs(&self, row: usize) -> f64 {
+        *self.rows[row].last().unwrap()
+    }
+
+    /// Reduced costs of every column for the given costs, with the negated objective value last
+    fn reduced_costs(&self, costs: &[f64]) -> Vec<f64> {
+        let mut reduced = costs.to_vec();
+        reduced.push(0.0);
+
+        for (row, basic) in self.rows.iter().zip(&self.basis) {
+            let cost = costs[*basic];
+            if cost != 0.0 {
+                reduced
+                    .iter_mut()
+                    .zip(row)
+                    .for_each(|(r, v)| *r -= cost * v);
+            }
+        }
+
+        reduced
+    }
+
+    fn pivot(&mut self, reduced: &mut [f64], row: usize, column: usize) {
+        let value = self.rows[row][column];
+        self.rows[row].iter_mut().for_each(|v| *v /= value);
+
+        let pivot_row = self.rows[row].clone();
+        let eliminate = |target: &mut [f64]| {
+            let factor = target[column];
+            if factor.abs() > EPSILON {
+                target
+                    .iter_mut()
+                    .zip(&pivot_row)
+                    .for_each(|(t, p)| *t -= factor * p);
+            }
+            target[column] = 0.0;
+        };
+
+        for (i, target) in self.rows.iter_mut().enumerate() {
+            if i != row {
+                eliminate(target);
+            }
+        }
+        eliminate(reduced);
+
+        self.rows[row][column] = 1.0;
+        self.basis[row] = column;
+    }
+
+    /// Pivots until no column below `allowed` can lower the objective. Uses Bland's rule, which
+    /// cannot cycle
+    fn optimize(&mut self, reduced: &mut [f64], allowed: usize) -> Result<(), IlpError> {
+        loop {
+            let Some(column) = (0..allowed).find(|j| reduced[*j] < -EPSILON) else {
+                return Ok(());
+            };
+
+            let row = (0..self.rows.len())
+                .filter(|i| self.rows[*i][column] > EPSILON)
+                .min_by(|a, b| {
+                    let ratio_a = self.rhs(*a) / self.rows[*a][column];
+                    let ratio_b = self.rhs(*b) / self.rows[*b][column];
+
+                    ratio_a
+                        .partial_cmp(&ratio_b)
+                        .unwrap()
+                        .then(self.basis[*a].cmp(&self.basis[*b]))
+                })
+                .ok_or(IlpError::Unbounded)?;
+
+            self.pivot(reduced, row, column);
+        }
+    }
+}
+
+/// Two phase simplex, minimizing costs · x over x >= 0
+fn simplex(costs: &[f64], constraints: &[(Vec<f64>, Relation, f64)]) -> Result<Vec<f64>, IlpError> {
+    let n = costs.len();
+    let m = constraints.len();
+
+    let slacks = constraints
+        .iter()
+        .filter(|(_, relation, _)| *relation != Relation::Equal)
+        .count();
+    let artificial_start = n + slacks;
+    let width = artificial_start + m;
+
+    let mut rows = vec![];
+    let mut basis = vec![];
+    let mut slack = n;
+
+    for (i, (coefficients, relation, rhs)) in constraints.iter().enumerate() {
+        // Keep the right hand side non-negative so the starting basis is feasible
+        let sign = if *rhs < 0.0 { -1.0 } else { 1.0 };
+        let relation = match (relation, sign < 0.0) {
+            (Relation::LessEq, true) => Relation::GreaterEq,
+            (Relation::GreaterEq, true) => Relation::LessEq,
+            (relation, _) => *relation,
+        };
+
+        let mut row = vec![0.0; width + 1];
+        row[..n]
+            .iter_mut()
+            .zip(coefficients)
+            .for_each(|(r, c)| *r = c * sign);
+        row[width] = rhs * sign;
+
+        match relation {
+            Relation::LessEq => {
+                row[slack] = 1.0;
+                basis.push(slack);
+                slack += 1;
+            }
+            Relation::GreaterEq => {
+                row[slack] = -1.0;
+                row[artificial_start + i] = 1.0;
+                basis.push(artificial_start + i);
+                slack += 1;
+            }
+            Relation::Equal => {
+                row[artificial_start + i] = 1.0;
+                basis.push(artificial_start + i);
+            }
+        }
+
+        rows.push(row);
+    }
+
+    let mut tableau = Tableau { rows, basis };
+
+    // Phase one: minimize the sum of the artificial variables to find a feasible basis
+    let mut phase_one = vec![0.0; width];
+    phase_one[artificial_start..]
+        .iter_mut()
+        .for_each(|c| *c = 1.0);
+
+    let mut reduced = tableau.reduced_costs(&phase_one);
+    tableau.optimize(&mut reduced, width)?;
+
+    if -reduced[width] > 1e-7 {
+        return Err(IlpError::Infeasible);
+    }
+
+    // Drive the remaining artificial variables out of the basis, dropping redundant rows
+    let mut row = 0;
+    while row < tableau.rows.len() {
+        if tableau.basis[row] < artificial_start {
+            row += 1;
+            continue;
+        }
+
+        match (0..artificial_start).find(|j| tableau.rows[row][*j].abs() > EPSILON) {
+            Some(column) => {
+                tableau.pivot(&mut reduced, row, column);
+                row += 1;
+            }
+            None => {
+                tableau.rows.remove(row);
+                tableau.basis.remove(row);
+            }
+        }
+    }
+
+    // Phase two: minimize the real objective without the artificial variables
+    let mut phase_two = costs.to_vec();
+    phase_two.resize(width, 0.0);
+
+    let mut reduced = tableau.reduced_costs(&phase_two);
+    tableau.optimize(&mut reduced, artificial_start)?;
+
+    let mut values = vec![0.0; n];
+    for (row, basic) in tableau.basis.iter().enumerate() {
+        if *basic < n {
+            values[*basic] = tableau.rhs(row);
+        }
+    }
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn solves_continuous_lp() {
+        // Classic example: maximize 3x + 5y with x <= 4, 2y <= 12, 3x + 2y <= 18
+        let mut problem = Problem::maximize();
+        let x = problem.add_variable(3.0);
+        let y = problem.add_variable(5.0);
+
+        problem.add_constraint(&[(x, 1.0)], Relation::LessEq, 4.0);
+        problem.add_constraint(&[(y, 2.0)], Relation::LessEq, 12.0);
+        problem.add_constraint(&[(x, 3.0), (y, 2.0)], Relation::LessEq, 18.0);
+
+        let solution = problem.solve().unwrap();
+
+        assert!((solution.objective - 36.0).abs() < 1e-6);
+        assert!((solution.value(x) - 2.0).abs() < 1e-6);
+        assert!((solution.value(y) - 6.0).abs() < 1e-6);
+    }
+
+    #[rstest]
+    fn branches_to_integer_solution() {
+        // The relaxation peaks at x = 1.5, y = 2.5 while the integer optimum is different
+        let mut problem = Problem::maximize();
+        let x = problem.add_integer(1.0);
+        let y = problem.add_integer(1.0);
+
+        problem.add_constraint(&[(x, -1.0), (y, 1.0)], Relation::LessEq, 1.0);
+        problem.add_constraint(&[(x, 3.0), (y, 2.0)], Relation::LessEq, 12.0);
+        problem.add_constraint(&[(x, 2.0), (y, 3.0)], Relation::LessEq, 12.0);
+
+        let solution = problem.solve().unwrap();
+
+        assert_eq!(solution.objective, 4.0);
+        assert!(solution.values.iter().all(|v| v.fract() == 0.0));
+    }
+
+    #[rstest]
+    fn respects_bounds_and_greater_eq() {
+        // Minimize x + y where x + y >= 7.5, 2 <= x <= 3 and y is integer
+        let mut problem = Problem::minimize();
+        let x = problem.add_variable(1.0);
+        let y = problem.add_integer(1.0);
+
+        problem.set_bounds(x, 2.0, 3.0);
+        problem.add_constraint(&[(x, 1.0), (y, 1.0)], Relation::GreaterEq, 7.5);
+
+        let solution = problem.solve().unwrap();
+
+        assert!((solution.objective - 7.5).abs() < 1e-6);
+        assert!(solution.value(x) >= 2.0 - 1e-6 && solution.value(x) <= 3.0 + 1e-6);
+        assert!((4..=5).contains(&solution.integer(y)));
+    }
+
+    #[rstest]
+    fn allows_negative_lower_bounds() {
+        let mut problem = Problem::minimize();
+        let x = problem.add_integer(1.0);
+
+        problem.set_bounds(x, -5.0, 5.0);
+        problem.add_constraint(&[(x, 2.0)], Relation::GreaterEq, -7.0);
+
+        assert_eq!(problem.solve().unwrap().integer(x), -3);
+    }
+
+    #[rstest]
+    fn detects_infeasible_problems() {
+        let mut problem = Problem::minimize();
+        let x = problem.add_variable(1.0);
+
+        problem.add_constraint(&[(x, 1.0)], Relation::GreaterEq, 5.0);
+        problem.add_constraint(&[(x, 1.0)], Relation::LessEq, 3.0);
+
+        assert_eq!(problem.solve(), Err(IlpError::Infeasible));
+
+        // Feasible as a relaxation, but 2x = 3 has no integer solution
+        let mut problem = Problem::minimize();
+        let x = problem.add_integer(1.0);
+        problem.add_constraint(&[(x, 2.0)], Relation::Equal, 3.0);
+
+        assert_eq!(problem.solve(), Err(IlpError::Infeasible));
+    }
+
+    #[rstest]
+    fn stops_at_node_limit() {
+        // 2x - 2y = 1 is feasible as a relaxation, but every branch keeps a fractional solution
+        let mut problem = Problem::minimize();
+        let x = problem.add_integer(0.0);
+        let y = problem.add_integer(0.0);
+        problem.add_constraint(&[(x, 2.0), (y, -2.0)], Relation::Equal, 1.0);
+        problem.set_node_limit(1_000);
+
+        assert_eq!(problem.solve(), Err(IlpError::NodeLimit));
+
+        // With finite bounds the same problem is proven infeasible
+        problem.set_bounds(x, 0.0, 10.0);
+        problem.set_bounds(y, 0.0, 10.0);
+
+        assert_eq!(problem.solve(), Err(IlpError::Infeasible));
+    }
+
+    #[rstest]
+    fn detects_unbounded_problems() {
+        let mut problem = Problem::maximize();
+        let x = problem.add_variable(1.0);
+        let y = problem.add_variable(0.0);
+
+        problem.add_constraint(&[(x, 1.0), (y, -1.0)], Relation::LessEq, 2.0);
+
+        assert_eq!(problem.solve(), Err(IlpError::Unbounded));
+    }
+
+    #[rstest]
+    fn solves_button_presses() {
+        // Buttons (3) (1,3) (2) (2,3) (0,2) (0,1) with joltage {3,5,4,7}
+        let buttons = [
+            vec![3],
+            vec![1, 3],
+            vec![2],
+            vec![2, 3],
+            vec![0, 2],
+            vec![0, 1],
+        ];
+        let joltage = [3.0, 5.0, 4.0, 7.0];
+
+        let mut problem = Problem::minimize();
+        let presses = buttons
+            .iter()
+            .map(|_| problem.add_integer(1.0))
+            .collect_vec();
+
+        for (counter, total) in joltage.iter().enumerate() {
+            let terms = buttons
+                .iter()
+                .zip(&presses)
+                .filter(|(button, _)| button.contains(&counter))
+                .map(|(_, press)| (*press, 1.0))
+                .collect_vec();
+
+            problem.add_constraint(&terms, Relation::Equal, *total);
+        }
+
+        assert_eq!(problem.solve().unwrap().objective, 10.0);
+    }
+}
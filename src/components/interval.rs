@@ -0,0 +1,297 @@
+use std::ops::RangeInclusive;
+
+/// Integer types that can be stored in an IntervalSet. Being discrete allows adjacent ranges like
+/// 1..=3 and 4..=6 to be coalesced into 1..=6
+pub trait Discrete: Copy + Ord {
+    /// The next value, None when at the maximum of the type
+    fn succ(self) -> Option<Self>;
+    /// The previous value, None when at the minimum of the type
+    fn pred(self) -> Option<Self>;
+    /// Number of values in start..=end
+    fn count(start: Self, end: Self) -> u64;
+}
+
+macro_rules! impl_discrete {
+    ($($t:ty),*) => {
+        $(
+            impl Discrete for $t {
+                fn succ(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn pred(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u64 {
+                    (end as i128 - start as i128 + 1) as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// A set of values stored as sorted, non-overlapping and non-adjacent inclusive ranges
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::interval::IntervalSet;
+///
+/// let mut set = IntervalSet::from_iter([3..=5, 10..=14, 16..=20, 12..=18]);
+/// assert_eq!(set.iter().collect::<Vec<_>>(), vec![3..=5, 10..=20]);
+/// assert_eq!(set.covered_len(), 14);
+///
+/// set.remove(4..=11);
+/// assert_eq!(set.iter().collect::<Vec<_>>(), vec![3..=3, 12..=20]);
+/// assert!(set.contains(15));
+/// assert!(!set.contains(4));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { ranges: vec![] }
+    }
+
+    /// Adds all values of the range to the set, merging with overlapping or adjacent ranges
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // First range that overlaps or touches the new range, followed by the first range that
+        // lies completely after it
+        let lo = self
+            .ranges
+            .partition_point(|r| r.1.succ().is_some_and(|next| next < start));
+        let hi = self
+            .ranges
+            .partition_point(|r| end.succ().is_none_or(|next| r.0 <= next));
+
+        let merged = if lo < hi {
+            (start.min(self.ranges[lo].0), end.max(self.ranges[hi - 1].1))
+        } else {
+            (start, end)
+        };
+
+        self.ranges.splice(lo..hi, [merged]);
+    }
+
+    /// Removes all values of the range from the set, splitting ranges where needed
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let lo = self.ranges.partition_point(|r| r.1 < start);
+        let hi = self.ranges.partition_point(|r| r.0 <= end);
+
+        if lo >= hi {
+            return;
+        }
+
+        let mut remaining = Vec::with_capacity(2);
+
+        let first = self.ranges[lo];
+        if first.0 < start {
+            remaining.push((first.0, start.pred().unwrap()));
+        }
+
+        let last = self.ranges[hi - 1];
+        if last.1 > end {
+            remaining.push((end.succ().unwrap(), last.1));
+        }
+
+        self.ranges.splice(lo..hi, remaining);
+    }
+
+    /// Checks if a value is part of the set in O(log n)
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|r| r.1 < value);
+
+        self.ranges.get(index).is_some_and(|r| r.0 <= value)
+    }
+
+    /// Returns all values that are in either set
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        other.iter().for_each(|r| result.insert(r));
+
+        result
+    }
+
+    /// Returns all values that are in both sets
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+
+            let start = a.0.max(b.0);
+            let end = a.1.min(b.1);
+            if start <= end {
+                ranges.push((start, end));
+            }
+
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { ranges }
+    }
+
+    /// Returns all values of self that are not in other
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        other.iter().for_each(|r| result.remove(r));
+
+        result
+    }
+
+    /// Total number of values covered by the set
+    pub fn covered_len(&self) -> u64 {
+        self.ranges.iter().map(|r| T::count(r.0, r.1)).sum()
+    }
+
+    /// Number of disjoint ranges in the set
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Iterates the disjoint ranges of the set in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> {
+        self.ranges.iter().map(|r| r.0..=r.1)
+    }
+}
+
+impl<T: Discrete> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Discrete> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+
+        set
+    }
+}
+
+impl<T: Discrete> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|r| self.insert(r));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt::Debug;
+
+    use itertools::Itertools;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn ranges<T: Discrete>(set: &IntervalSet<T>) -> Vec<RangeInclusive<T>> {
+        set.iter().collect_vec()
+    }
+
+    #[rstest]
+    #[case(vec![1..=3, 5..=7], vec![1..=3, 5..=7])]
+    #[case(vec![1..=3, 4..=7], vec![1..=7])]
+    #[case(vec![5..=7, 1..=3, 2..=5], vec![1..=7])]
+    #[case(vec![1..=10, 3..=4], vec![1..=10])]
+    #[case(vec![3..=4, 8..=9, 1..=10], vec![1..=10])]
+    #[case(vec![1..=1, 3..=3, 5..=5, 2..=2], vec![1..=3, 5..=5])]
+    #[case(vec![RangeInclusive::new(5, 1)], vec![])]
+    #[case(
+        vec![354113252785914u64..=354113252785914, 354113252785914..=359458697423182],
+        vec![354113252785914..=359458697423182]
+    )]
+    #[case(
+        vec![354113252785914u64..=359458697423182, 354113252785914..=354113252785914],
+        vec![354113252785914..=359458697423182]
+    )]
+    #[case(
+        vec![418784088040056u64..=419809022460311, 418978112767017..=419296794005487],
+        vec![418784088040056..=419809022460311]
+    )]
+    fn it_coalesces_on_insert<T: Discrete + Debug>(
+        #[case] input: Vec<RangeInclusive<T>>,
+        #[case] result: Vec<RangeInclusive<T>>,
+    ) {
+        assert_eq!(ranges(&IntervalSet::from_iter(input)), result);
+    }
+
+    #[rstest]
+    #[case(vec![1..=10], 3..=5, vec![1..=2, 6..=10])]
+    #[case(vec![1..=10], 1..=5, vec![6..=10])]
+    #[case(vec![1..=10], 0..=20, vec![])]
+    #[case(vec![1..=3, 5..=7, 9..=11], 2..=10, vec![1..=1, 11..=11])]
+    #[case(vec![1..=3, 9..=11], 5..=7, vec![1..=3, 9..=11])]
+    fn it_splits_on_remove(
+        #[case] input: Vec<RangeInclusive<i32>>,
+        #[case] remove: RangeInclusive<i32>,
+        #[case] result: Vec<RangeInclusive<i32>>,
+    ) {
+        let mut set = IntervalSet::from_iter(input);
+        set.remove(remove);
+
+        assert_eq!(ranges(&set), result);
+    }
+
+    #[rstest]
+    fn it_handles_type_limits() {
+        let mut set = IntervalSet::from_iter([250u8..=255, 0..=3]);
+        set.insert(4..=249);
+
+        assert_eq!(ranges(&set), vec![0..=255]);
+        assert_eq!(set.covered_len(), 256);
+
+        set.remove(0..=0);
+        set.remove(255..=255);
+        assert_eq!(ranges(&set), vec![1..=254]);
+    }
+
+    #[rstest]
+    fn it_checks_membership() {
+        let set = IntervalSet::from_iter([3u64..=5, 10..=14, 16..=20, 12..=18]);
+
+        let ingredients = [1, 5, 8, 11, 17, 32];
+        let fresh = ingredients.iter().filter(|i| set.contains(**i)).count();
+
+        assert_eq!(fresh, 3);
+        assert_eq!(set.covered_len(), 14);
+        assert_eq!(set.len(), 2);
+    }
+
+    #[rstest]
+    fn it_supports_set_operations() {
+        let a = IntervalSet::from_iter([0..=10, 20..=30]);
+        let b = IntervalSet::from_iter([5..=25, 40..=50]);
+
+        assert_eq!(ranges(&a.union(&b)), vec![0..=30, 40..=50]);
+        assert_eq!(ranges(&a.intersection(&b)), vec![5..=10, 20..=25]);
+        assert_eq!(ranges(&a.difference(&b)), vec![0..=4, 26..=30]);
+        assert_eq!(ranges(&b.difference(&a)), vec![11..=19, 40..=50]);
+
+        assert!(a.intersection(&IntervalSet::new()).is_empty());
+    }
+}
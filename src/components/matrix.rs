@@ -1,215 +1,699 @@
-use std::{fmt::Display, ops::Rem, option::Option, vec::Vec};
+use std::{
+    fmt::Display,
+    ops::{Add, Index, IndexMut, Mul, Sub},
+    option::Option,
+    vec::Vec,
+};
 
 use itertools::Itertools;
 
-#[derive(Clone)]
-pub struct Matrix(Vec<Vec<isize>>);
+use super::rational::{Rational, gcd};
 
-impl Matrix {
-    pub fn empty(n: usize, m: usize) -> Self {
-        Matrix(vec![vec![0; n]; m])
+/// Values that can be stored in a Matrix
+pub trait Element:
+    Copy + PartialEq + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! impl_element {
+    ($($t:ty),*) => {
+        $(
+            impl Element for $t {
+                const ZERO: Self = 0 as $t;
+                const ONE: Self = 1 as $t;
+            }
+        )*
+    };
+}
+
+impl_element!(i32, i64, i128, isize, u32, u64, u128, usize, f64);
+
+impl Element for Rational {
+    const ZERO: Self = Rational::ZERO;
+    const ONE: Self = Rational::ONE;
+}
+
+/// A dense matrix stored as rows. Indexing with (row, column) panics when out of range
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<T = isize>(Vec<Vec<T>>);
+
+/// Reasons a system of equations has no (usable) solution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveError {
+    /// The equations contradict each other
+    Inconsistent,
+    /// A free variable has no upper bound, so its values cannot be enumerated
+    Unbounded,
+    /// The system has solutions, but none of them are non-negative integers
+    Infeasible,
+    /// The matrix has no columns, so there is no right hand side to solve for
+    Empty,
+}
+
+/// The general solution of an augmented system [A | b]. Every solution is `particular` plus some
+/// combination of the `nullspace` vectors
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solution {
+    /// The solution with every free variable set to zero
+    pub particular: Vec<Rational>,
+    /// Basis of the nullspace of A, one vector per free variable
+    pub nullspace: Vec<Vec<Rational>>,
+    /// Variables determined by the free variables
+    pub pivots: Vec<usize>,
+    /// Variables that can take any value
+    pub free: Vec<usize>,
+}
+
+impl Solution {
+    /// The rank of A
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
     }
 
-    fn pivot_index(&self, n: usize) -> Option<usize> {
-        self.0
-            .iter()
-            .enumerate()
-            .skip(n)
-            .find(|(_, v)| {
-                let Some(first) = v.iter().find(|v| **v != 0) else {
-                    return false;
-                };
+    pub fn is_unique(&self) -> bool {
+        self.free.is_empty()
+    }
+}
 
-                if *v.get(n).unwrap() == 0 {
-                    return false;
-                }
+impl<T: Element> Matrix<T> {
+    /// Creates a matrix of m rows by n columns, filled with zeroes
+    pub fn empty(n: usize, m: usize) -> Self {
+        Matrix(vec![vec![T::ZERO; n]; m])
+    }
 
-                true
-            })
-            .map(|v| v.0)
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = Self::empty(n, n);
+        (0..n).for_each(|i| matrix.0[i][i] = T::ONE);
+
+        matrix
     }
 
-    pub fn append(&mut self, rhs: Matrix) {
+    /// Appends the columns of rhs to the right of this matrix
+    pub fn append(&mut self, rhs: Matrix<T>) {
         assert_eq!(self.0.len(), rhs.0.len());
 
         self.0.iter_mut().zip(rhs.0).for_each(|(l, r)| l.extend(r));
     }
 
-    fn switch(&mut self, lhs: usize, rhs: usize) {
-        if lhs == rhs {
-            return;
-        }
-        let [first, second] = self.0.get_disjoint_mut([lhs, rhs]).unwrap();
-        std::mem::swap(first, second);
+    fn m(&self) -> usize {
+        self.0.len()
     }
 
-    fn sub(&mut self, lhs: usize, rhs: usize) {
-        if lhs == rhs {
-            return;
-        }
+    fn n(&self) -> usize {
+        self.0.first().map_or(0, |row| row.len())
+    }
 
-        let [lhs, rhs] = self.0.get_disjoint_mut([lhs, rhs]).unwrap();
+    /// Number of rows
+    pub fn rows(&self) -> usize {
+        self.m()
+    }
 
-        lhs.iter_mut().zip(rhs).for_each(|(lhs, rhs)| {
-            *lhs -= *rhs;
-        });
+    /// Number of columns
+    pub fn columns(&self) -> usize {
+        self.n()
     }
 
-    fn add(&mut self, lhs: usize, rhs: usize) {
-        if lhs == rhs {
-            return;
-        }
+    pub fn is_square(&self) -> bool {
+        self.m() == self.n()
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.0[row]
+    }
+
+    pub fn transpose(&self) -> Matrix<T> {
+        Matrix(
+            (0..self.n())
+                .map(|c| self.0.iter().map(|row| row[c]).collect())
+                .collect(),
+        )
+    }
 
-        let [lhs, rhs] = self.0.get_disjoint_mut([lhs, rhs]).unwrap();
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        self.0.swap(a, b);
+    }
 
-        lhs.iter_mut().zip(rhs).for_each(|(lhs, rhs)| {
-            *lhs += *rhs;
-        });
+    /// Multiplies every value of a row by factor
+    pub fn scale_row(&mut self, row: usize, factor: T) {
+        self.0[row].iter_mut().for_each(|v| *v = *v * factor);
     }
 
-    fn flip_signs(&mut self, lhs: usize) {
-        self.0
-            .get_mut(lhs)
-            .unwrap()
+    /// Adds factor times the source row to the target row
+    /// Panics if target and source are the same row
+    pub fn add_row(&mut self, target: usize, source: usize, factor: T) {
+        let [target, source] = self.0.get_disjoint_mut([target, source]).unwrap();
+
+        target
             .iter_mut()
-            .for_each(|v| *v = -*v);
+            .zip(source.iter())
+            .for_each(|(t, s)| *t = *t + *s * factor);
     }
 
-    fn get(&self, n: usize, m: usize) -> isize {
-        *self.0.get(m).unwrap().get(n).unwrap()
+    /// Raises a square matrix to the power exp by repeated squaring, useful to jump ahead in
+    /// linear recurrences
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::components::matrix::Matrix;
+    ///
+    /// let fibonacci = Matrix::from(vec![vec![1, 1], vec![1, 0]]);
+    /// assert_eq!(fibonacci.pow(10)[(0, 1)], 55);
+    /// ```
+    pub fn pow(&self, exp: u64) -> Matrix<T> {
+        self.pow_by(exp, |a, b| a * b)
     }
 
-    fn row_echelon_row(&mut self, m: usize) {
-        if let Some(pivot) = self.pivot_index(m) {
-            self.switch(m, pivot);
-            if self.get(m, m) < 0 {
-                self.flip_signs(m);
+    fn pow_by<F>(&self, mut exp: u64, mul: F) -> Matrix<T>
+    where
+        F: Fn(&Matrix<T>, &Matrix<T>) -> Matrix<T>,
+    {
+        assert!(
+            self.is_square(),
+            "only square matrices can be raised to a power"
+        );
+
+        let mut result = Self::identity(self.m());
+        let mut base = self.clone();
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = mul(&result, &base);
             }
-        };
+            base = mul(&base, &base);
+            exp >>= 1;
+        }
 
-        while self.get(m, m) > 1 {
-            let Some(rhs) = self
-                .0
+        result
+    }
+}
+
+impl Matrix<isize> {
+    /// Determinant of a square matrix, calculated exactly with Bareiss' fraction-free elimination
+    pub fn determinant(&self) -> isize {
+        assert!(self.is_square(), "only square matrices have a determinant");
+
+        let n = self.m();
+        let mut rows = self
+            .0
+            .iter()
+            .map(|row| row.iter().map(|v| *v as i128).collect_vec())
+            .collect_vec();
+        let mut sign = 1;
+        let mut previous = 1;
+
+        for k in 0..n.saturating_sub(1) {
+            if rows[k][k] == 0 {
+                let Some(swap) = (k + 1..n).find(|i| rows[*i][k] != 0) else {
+                    return 0;
+                };
+
+                rows.swap(k, swap);
+                sign = -sign;
+            }
+
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    rows[i][j] = (rows[i][j] * rows[k][k] - rows[i][k] * rows[k][j]) / previous;
+                }
+            }
+
+            previous = rows[k][k];
+        }
+
+        match n {
+            0 => 1,
+            _ => (sign * rows[n - 1][n - 1]) as isize,
+        }
+    }
+
+    pub fn to_rational(&self) -> Matrix<Rational> {
+        Matrix(
+            self.0
                 .iter()
-                .enumerate()
-                .skip(m + 1)
-                .find(|(_, v)| v[m] != 0)
-            else {
+                .map(|row| row.iter().map(|v| Rational::from(*v)).collect())
+                .collect(),
+        )
+    }
+
+    /// Inverse of a square matrix, None if the matrix is singular
+    pub fn inverse(&self) -> Option<Matrix<Rational>> {
+        self.to_rational().inverse()
+    }
+
+    /// Raises a square matrix to the power exp, keeping every value within 0..modulus
+    pub fn pow_mod(&self, exp: u64, modulus: isize) -> Matrix<isize> {
+        assert!(modulus > 0, "modulus must be positive");
+
+        let mut base = self.clone();
+        base.0
+            .iter_mut()
+            .flatten()
+            .for_each(|v| *v = v.rem_euclid(modulus));
+
+        base.pow_by(exp, |a, b| a.mul_mod(b, modulus))
+    }
+
+    fn mul_mod(&self, rhs: &Matrix<isize>, modulus: isize) -> Matrix<isize> {
+        assert_eq!(self.n(), rhs.m(), "matrix dimensions do not match");
+
+        let modulus = modulus as i128;
+        Matrix(
+            self.0
+                .iter()
+                .map(|row| {
+                    (0..rhs.n())
+                        .map(|c| {
+                            row.iter()
+                                .zip(&rhs.0)
+                                .map(|(a, r)| *a as i128 * r[c] as i128 % modulus)
+                                .sum::<i128>()
+                                .rem_euclid(modulus) as isize
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+    }
+
+    /// Fraction-free Gauss-Jordan elimination over the first `columns` columns. Every pivot is
+    /// positive and the only non-zero value in its column, rows are divided by the gcd of their
+    /// values to keep them small. Returns the pivot column of every non-zero row, in row order
+    fn eliminate(&mut self, columns: usize) -> Vec<usize> {
+        let mut pivots = vec![];
+
+        for column in 0..columns {
+            let row = pivots.len();
+            if row == self.m() {
                 break;
+            }
+
+            let Some(pivot) = (row..self.m())
+                .filter(|r| self.0[*r][column] != 0)
+                .min_by_key(|r| self.0[*r][column].abs())
+            else {
+                continue;
             };
 
-            if rhs.1[m] > 0 {
-                self.sub(m, rhs.0);
-            } else {
-                self.add(m, rhs.0);
+            self.0.swap(row, pivot);
+            if self.0[row][column] < 0 {
+                self.0[row].iter_mut().for_each(|v| *v = -*v);
             }
-        }
+            reduce(&mut self.0[row]);
 
-        for i in m + 1..self.m() {
-            while self.get(m, i) > 0 {
-                self.sub(i, m);
-            }
-            while self.get(m, i) < 0 {
-                self.add(i, m);
+            for other in 0..self.m() {
+                let factor = self.0[other][column];
+                if other == row || factor == 0 {
+                    continue;
+                }
+
+                let [target, source] = self.0.get_disjoint_mut([other, row]).unwrap();
+                let pivot_value = source[column];
+
+                target
+                    .iter_mut()
+                    .zip(source.iter())
+                    .for_each(|(t, s)| *t = *t * pivot_value - *s * factor);
+                reduce(target);
             }
+
+            pivots.push(column);
         }
+
+        pivots
     }
 
-    fn m(&self) -> usize {
-        self.0.len()
+    /// Brings the matrix into reduced row echelon form, scaled to integers. Returns the pivot
+    /// column of every non-zero row
+    pub fn row_echelon(&mut self) -> Vec<usize> {
+        self.eliminate(self.n())
     }
 
-    fn first_non_empty(&self) -> Option<&Vec<isize>> {
-        self.0.iter().rev().find(|v| v.iter().any(|n| *n != 0))
+    pub fn rank(&self) -> usize {
+        self.clone().row_echelon().len()
     }
 
-    pub fn solve(&mut self) -> Option<usize> {
-        // println!("{}", self);
+    /// Number of variables when read as an augmented system, all columns but the last
+    fn variables(&self) -> Result<usize, SolveError> {
+        self.n().checked_sub(1).ok_or(SolveError::Empty)
+    }
 
-        let Some(row) = self.first_non_empty() else {
-            return Some(0);
-        };
+    /// Eliminates the matrix as an augmented system, returning the reduced matrix, the pivot
+    /// variables and the free variables
+    fn reduce_system(&self) -> Result<(Matrix, Vec<usize>, Vec<usize>), SolveError> {
+        let columns = self.variables()?;
+        let mut reduced = self.clone();
+        let pivots = reduced.eliminate(columns);
 
-        let unknowns = find_unknowns(row);
-        // dbg!(&unknowns);
+        // A row without any coefficients left reads 0 = b
+        if reduced.0[pivots.len()..]
+            .iter()
+            .any(|row| row[columns] != 0)
+        {
+            return Err(SolveError::Inconsistent);
+        }
 
-        if unknowns.len() == 1 {
-            let unknown = *unknowns.first().unwrap();
-            let x_count = *row.get(unknown).unwrap();
-            let count = *row.last().unwrap() / x_count;
-            if count < 0 {
-                return None;
-            }
+        let free = (0..columns).filter(|c| !pivots.contains(c)).collect_vec();
 
-            self.back_substitute(unknown, count);
+        Ok((reduced, pivots, free))
+    }
 
-            return self.solve().map(|v| v + (count as usize));
+    /// Solves the matrix as an augmented system [A | b], where the last column holds b
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::components::{matrix::Matrix, rational::Rational};
+    ///
+    /// // x + y = 3, x - y = 1
+    /// let matrix = Matrix::from(vec![vec![1, 1, 3], vec![1, -1, 1]]);
+    /// let solution = matrix.solve().unwrap();
+    ///
+    /// assert!(solution.is_unique());
+    /// assert_eq!(solution.particular, vec![Rational::from(2), Rational::from(1)]);
+    /// ```
+    pub fn solve(&self) -> Result<Solution, SolveError> {
+        let columns = self.variables()?;
+        let (reduced, pivots, free) = self.reduce_system()?;
+        let rows = &reduced.0[..pivots.len()];
+
+        let mut particular = vec![Rational::ZERO; columns];
+        for (row, pivot) in rows.iter().zip(&pivots) {
+            particular[*pivot] = Rational::new(row[columns], row[*pivot]);
         }
 
-        if unknowns.is_empty() {
-            return None;
+        let nullspace = free
+            .iter()
+            .map(|f| {
+                let mut vector = vec![Rational::ZERO; columns];
+                vector[*f] = Rational::ONE;
+
+                for (row, pivot) in rows.iter().zip(&pivots) {
+                    vector[*pivot] = Rational::new(-row[*f], row[*pivot]);
+                }
+
+                vector
+            })
+            .collect_vec();
+
+        Ok(Solution {
+            particular,
+            nullspace,
+            pivots,
+            free,
+        })
+    }
+
+    /// Finds the non-negative integer solution of the augmented system [A | b] with the lowest
+    /// value for `objective` · x, returning that value and the solution.
+    ///
+    /// Free variables are enumerated, so each needs an upper bound. A bound is taken from every
+    /// equation where all coefficients share a sign, like a sum of presses adding up to a total.
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::components::matrix::Matrix;
+    ///
+    /// // x + y = 4, y + z = 3, minimize x + y + z
+    /// let matrix = Matrix::from(vec![vec![1, 1, 0, 4], vec![0, 1, 1, 3]]);
+    ///
+    /// assert_eq!(matrix.minimize(&[1, 1, 1]), Ok((4, vec![1, 3, 0])));
+    /// ```
+    pub fn minimize(&self, objective: &[isize]) -> Result<(isize, Vec<isize>), SolveError> {
+        let columns = self.variables()?;
+        assert_eq!(
+            objective.len(),
+            columns,
+            "objective must cover every variable"
+        );
+
+        let bounds = self.upper_bounds();
+
+        let (reduced, pivots, free) = self.reduce_system()?;
+        let free_bounds = free
+            .iter()
+            .map(|f| bounds[*f].ok_or(SolveError::Unbounded))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // A row can be checked as soon as the last free variable it uses has been assigned
+        let rows = &reduced.0[..pivots.len()];
+        let mut checks = vec![vec![]; free.len() + 1];
+        for (r, row) in rows.iter().enumerate() {
+            let depth = free.iter().rposition(|f| row[*f] != 0).map_or(0, |i| i + 1);
+            checks[depth].push(r);
         }
 
-        let mut min = None;
+        let mut search = IntegerSearch {
+            rows,
+            pivots: &pivots,
+            free: &free,
+            bounds: free_bounds,
+            checks,
+            objective,
+            monotone: objective.iter().all(|c| *c >= 0),
+            values: vec![0; columns],
+            best: None,
+        };
+        search.search(0, 0);
 
-        for unknown in unknowns {
-            let mut attempt = 0;
-            loop {
-                let mut matrix = self.clone();
-                // println!("{unknown} {attempt}");
+        search.best.ok_or(SolveError::Infeasible)
+    }
 
-                matrix.back_substitute(unknown, attempt);
+    /// Upper bound for every variable that can be derived from a single equation, assuming all
+    /// variables are non-negative. Expects at least one column
+    fn upper_bounds(&self) -> Vec<Option<isize>> {
+        let columns = self.n() - 1;
+        let mut bounds = vec![None; columns];
+
+        for row in &self.0 {
+            let sign = if row[..columns].iter().all(|v| *v >= 0) {
+                1
+            } else if row[..columns].iter().all(|v| *v <= 0) {
+                -1
+            } else {
+                continue;
+            };
 
-                if let Some(result) = matrix.solve() {
-                    min = Some(min.unwrap_or(usize::MAX).min((attempt as usize) + result));
-                };
+            let total = row[columns] * sign;
+            for (bound, value) in bounds.iter_mut().zip(&row[..columns]) {
+                let value = value * sign;
+                if value > 0 {
+                    let limit = (total / value).max(-1);
+                    *bound = Some(bound.map_or(limit, |b: isize| b.min(limit)));
+                }
+            }
+        }
+
+        bounds
+    }
+}
+
+impl Matrix<Rational> {
+    /// Determinant of a square matrix using Gaussian elimination
+    pub fn determinant(&self) -> Rational {
+        assert!(self.is_square(), "only square matrices have a determinant");
+
+        let mut matrix = self.clone();
+        let mut determinant = Rational::ONE;
+
+        for k in 0..matrix.m() {
+            let Some(pivot) = (k..matrix.m()).find(|i| !matrix.0[*i][k].is_zero()) else {
+                return Rational::ZERO;
+            };
+
+            if pivot != k {
+                matrix.swap_rows(k, pivot);
+                determinant = -determinant;
+            }
 
-                attempt += 1;
+            let value = matrix.0[k][k];
+            determinant = determinant * value;
 
-                if attempt > 100 {
-                    break;
+            for i in k + 1..matrix.m() {
+                let factor = matrix.0[i][k] / value;
+                matrix.add_row(i, k, -factor);
+            }
+        }
+
+        determinant
+    }
+
+    /// Inverse of a square matrix using Gauss-Jordan elimination, None if the matrix is singular
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::components::matrix::Matrix;
+    ///
+    /// let matrix = Matrix::from(vec![vec![2, 1], vec![1, 1]]);
+    /// let inverse = matrix.inverse().unwrap();
+    ///
+    /// assert_eq!(&matrix.to_rational() * &inverse, Matrix::identity(2));
+    /// ```
+    pub fn inverse(&self) -> Option<Matrix<Rational>> {
+        assert!(self.is_square(), "only square matrices have an inverse");
+
+        let n = self.m();
+        let mut matrix = self.clone();
+        matrix.append(Matrix::identity(n));
+
+        for k in 0..n {
+            let pivot = (k..n).find(|i| !matrix.0[*i][k].is_zero())?;
+            matrix.swap_rows(k, pivot);
+
+            let value = matrix.0[k][k];
+            matrix.scale_row(k, value.recip());
+
+            for i in (0..n).filter(|i| *i != k) {
+                let factor = matrix.0[i][k];
+                if !factor.is_zero() {
+                    matrix.add_row(i, k, -factor);
                 }
             }
         }
 
-        min
+        Some(Matrix(
+            matrix.0.into_iter().map(|row| row[n..].to_vec()).collect(),
+        ))
+    }
+}
+
+/// Divides a row by the gcd of its values
+fn reduce(row: &mut [isize]) {
+    let divisor = row.iter().fold(0, |acc, v| gcd(acc, *v));
+    if divisor > 1 {
+        row.iter_mut().for_each(|v| *v /= divisor);
     }
+}
+
+/// Depth first enumeration of the free variables of a reduced system, used by Matrix::minimize
+struct IntegerSearch<'a> {
+    rows: &'a [Vec<isize>],
+    pivots: &'a [usize],
+    free: &'a [usize],
+    bounds: Vec<isize>,
+    /// Rows to resolve after assigning the given number of free variables
+    checks: Vec<Vec<usize>>,
+    objective: &'a [isize],
+    /// When no objective coefficient is negative, a partial cost can be compared to the best
+    monotone: bool,
+    values: Vec<isize>,
+    best: Option<(isize, Vec<isize>)>,
+}
 
-    pub fn back_substitute(&mut self, n: usize, rhs: isize) {
-        for row in &mut self.0 {
-            let reff = row.get_mut(n).unwrap();
-            let count = *reff;
-            *reff = 0;
+impl IntegerSearch<'_> {
+    /// Calculates the pivot variable of a row from the assigned free variables, returning false
+    /// if it is not a non-negative integer
+    fn resolve(&mut self, r: usize) -> bool {
+        let row = &self.rows[r];
+        let pivot = self.pivots[r];
 
-            *row.last_mut().unwrap() -= count * rhs;
+        let total = row.last().unwrap()
+            - self
+                .free
+                .iter()
+                .map(|f| row[*f] * self.values[*f])
+                .sum::<isize>();
+
+        if total < 0 || total % row[pivot] != 0 {
+            return false;
         }
+
+        self.values[pivot] = total / row[pivot];
+        true
+    }
+
+    fn cost(&self) -> isize {
+        self.objective
+            .iter()
+            .zip(&self.values)
+            .map(|(c, v)| c * v)
+            .sum()
     }
 
-    pub fn row_echelon(&mut self) {
-        let nm = self.0.len().min(self.0.first().unwrap().len());
-        for m in 0..nm {
-            self.row_echelon_row(m);
+    fn search(&mut self, depth: usize, partial: isize) {
+        for r in self.checks[depth].clone() {
+            if !self.resolve(r) {
+                return;
+            }
+        }
+
+        if depth == self.free.len() {
+            let cost = self.cost();
+            if self.best.as_ref().is_none_or(|(best, _)| cost < *best) {
+                self.best = Some((cost, self.values.clone()));
+            }
+            return;
+        }
+
+        let variable = self.free[depth];
+        for value in 0..=self.bounds[depth] {
+            let partial = partial + self.objective[variable] * value;
+            if self.monotone && self.best.as_ref().is_some_and(|(best, _)| partial >= *best) {
+                break;
+            }
+
+            self.values[variable] = value;
+            self.search(depth + 1, partial);
         }
+
+        self.values[variable] = 0;
     }
 }
 
-fn find_unknowns(row: &[isize]) -> Vec<usize> {
-    let last = row.len() - 1;
-    row.iter()
-        .enumerate()
-        .filter(|v| *v.1 != 0 && v.0 != last)
-        .map(|v| v.0)
-        .collect_vec()
+impl<T: Element> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(self.n(), rhs.m(), "matrix dimensions do not match");
+
+        Matrix(
+            self.0
+                .iter()
+                .map(|row| {
+                    (0..rhs.n())
+                        .map(|c| {
+                            row.iter()
+                                .zip(&rhs.0)
+                                .fold(T::ZERO, |acc, (a, r)| acc + *a * r[c])
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+    }
 }
 
-impl From<Vec<isize>> for Matrix {
-    fn from(value: Vec<isize>) -> Self {
+impl<T: Element> Mul for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        &self.0[row][column]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        &mut self.0[row][column]
+    }
+}
+
+impl<T: Copy> From<Vec<T>> for Matrix<T> {
+    /// Creates a single column matrix
+    fn from(value: Vec<T>) -> Self {
         Matrix(value.iter().map(|v| Vec::from([*v])).collect_vec())
     }
 }
 
-impl From<Vec<Vec<isize>>> for Matrix {
-    fn from(value: Vec<Vec<isize>>) -> Self {
+impl<T> From<Vec<Vec<T>>> for Matrix<T> {
+    fn from(value: Vec<Vec<T>>) -> Self {
         let first_len = value.first().unwrap().len();
         assert!(value.iter().all(|v| v.len() == first_len));
 
@@ -217,7 +701,7 @@ impl From<Vec<Vec<isize>>> for Matrix {
     }
 }
 
-impl Display for Matrix {
+impl<T: Display> Display for Matrix<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for line in &self.0 {
             writeln!(f, "{}", line.iter().join(" "))?;
@@ -226,3 +710,196 @@ impl Display for Matrix {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn integers(values: &[isize]) -> Vec<Rational> {
+        values.iter().map(|v| Rational::from(*v)).collect()
+    }
+
+    #[rstest]
+    fn row_echelon_reduces_every_pivot_column() {
+        let mut matrix = Matrix::from(vec![vec![2, 4, 6], vec![1, 3, 5], vec![3, 7, 11]]);
+
+        let pivots = matrix.row_echelon();
+
+        assert_eq!(pivots, vec![0, 1]);
+        assert_eq!(
+            matrix,
+            Matrix::from(vec![vec![1, 0, -1], vec![0, 1, 2], vec![0, 0, 0]])
+        );
+        assert_eq!(matrix.rank(), 2);
+    }
+
+    #[rstest]
+    fn solves_unique_rational_system() {
+        // 2x + y = 1, x + 3y = 2
+        let matrix = Matrix::from(vec![vec![2, 1, 1], vec![1, 3, 2]]);
+
+        let solution = matrix.solve().unwrap();
+
+        assert!(solution.is_unique());
+        assert_eq!(solution.rank(), 2);
+        assert_eq!(
+            solution.particular,
+            vec![Rational::new(1, 5), Rational::new(3, 5)]
+        );
+    }
+
+    #[rstest]
+    fn solves_underdetermined_system() {
+        // Buttons (3) (1,3) (2) (2,3) (0,2) (0,1) with joltage {3,5,4,7}
+        let matrix = Matrix::from(vec![
+            vec![0, 0, 0, 0, 1, 1, 3],
+            vec![0, 1, 0, 0, 0, 1, 5],
+            vec![0, 0, 1, 1, 1, 0, 4],
+            vec![1, 1, 0, 1, 0, 0, 7],
+        ]);
+
+        let solution = matrix.solve().unwrap();
+
+        assert_eq!(solution.rank(), 4);
+        assert_eq!(solution.free.len(), 2);
+        assert_eq!(solution.nullspace.len(), 2);
+
+        // A · v = 0 for every nullspace vector
+        for vector in &solution.nullspace {
+            for row in &matrix.0 {
+                let total = row
+                    .iter()
+                    .zip(vector)
+                    .fold(Rational::ZERO, |acc, (a, v)| acc + Rational::from(*a) * *v);
+                assert!(total.is_zero());
+            }
+        }
+
+        assert_eq!(matrix.minimize(&[1; 6]).unwrap().0, 10);
+    }
+
+    #[rstest]
+    fn solves_consistent_overdetermined_system() {
+        // x + y = 3, x - y = 1, 2x + y = 5
+        let matrix = Matrix::from(vec![vec![1, 1, 3], vec![1, -1, 1], vec![2, 1, 5]]);
+
+        let solution = matrix.solve().unwrap();
+
+        assert_eq!(solution.particular, integers(&[2, 1]));
+        assert!(solution.is_unique());
+        assert_eq!(matrix.minimize(&[1, 1]), Ok((3, vec![2, 1])));
+    }
+
+    #[rstest]
+    fn detects_inconsistent_system() {
+        // x + y = 3, x - y = 1, 2x + y = 6
+        let matrix = Matrix::from(vec![vec![1, 1, 3], vec![1, -1, 1], vec![2, 1, 6]]);
+
+        assert_eq!(matrix.solve(), Err(SolveError::Inconsistent));
+        assert_eq!(matrix.minimize(&[1, 1]), Err(SolveError::Inconsistent));
+    }
+
+    #[rstest]
+    fn detects_infeasible_and_unbounded_systems() {
+        // 2x + 2y = 3 has only fractional solutions
+        let matrix = Matrix::from(vec![vec![2, 2, 3]]);
+        assert_eq!(matrix.minimize(&[1, 1]), Err(SolveError::Infeasible));
+
+        // x - y = 1 allows y to grow forever
+        let matrix = Matrix::from(vec![vec![1, -1, 1]]);
+        assert!(matrix.solve().is_ok());
+        assert_eq!(matrix.minimize(&[1, 1]), Err(SolveError::Unbounded));
+    }
+
+    #[rstest]
+    fn rejects_matrices_without_columns() {
+        let matrix: Matrix = Matrix::from(vec![vec![], vec![]]);
+
+        assert_eq!(matrix.solve(), Err(SolveError::Empty));
+        assert_eq!(matrix.minimize(&[]), Err(SolveError::Empty));
+        assert_eq!(Matrix::<isize>::empty(3, 0).solve(), Err(SolveError::Empty));
+    }
+
+    #[rstest]
+    fn minimize_respects_objective() {
+        // x + y + z = 6 where z costs the least
+        let matrix = Matrix::from(vec![vec![1, 1, 1, 6]]);
+
+        assert_eq!(matrix.minimize(&[3, 2, 1]), Ok((6, vec![0, 0, 6])));
+        assert_eq!(matrix.minimize(&[1, 2, 3]), Ok((6, vec![6, 0, 0])));
+        assert_eq!(matrix.minimize(&[-1, 0, 0]), Ok((-6, vec![6, 0, 0])));
+    }
+
+    #[rstest]
+    fn multiplies_and_transposes() {
+        let a = Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let b = Matrix::from(vec![vec![7, 8], vec![9, 10], vec![11, 12]]);
+
+        assert_eq!(&a * &b, Matrix::from(vec![vec![58, 64], vec![139, 154]]));
+        assert_eq!(a.transpose().transpose(), a);
+        assert_eq!(a.transpose()[(2, 1)], 6);
+        assert_eq!((a.rows(), a.columns()), (2, 3));
+        assert_eq!(&b * &Matrix::identity(2), b);
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn mul_should_panic_on_dimension_mismatch() {
+        let a = Matrix::from(vec![vec![1, 2, 3]]);
+
+        let _ = &a * &a;
+    }
+
+    #[rstest]
+    #[case(vec![vec![3]], 3)]
+    #[case(vec![vec![1, 2], vec![3, 4]], -2)]
+    #[case(vec![vec![0, 1], vec![1, 0]], -1)]
+    #[case(vec![vec![2, 0, 1], vec![1, 3, 2], vec![1, 1, 1]], 0)]
+    #[case(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]], 0)]
+    #[case(vec![vec![0, 2, 1, 3], vec![1, 0, 2, 1], vec![3, 1, 0, 2], vec![2, 3, 1, 0]], -62)]
+    fn calculates_determinant(#[case] input: Vec<Vec<isize>>, #[case] expected: isize) {
+        let matrix = Matrix::from(input);
+
+        assert_eq!(matrix.determinant(), expected);
+        assert_eq!(matrix.to_rational().determinant(), Rational::from(expected));
+    }
+
+    #[rstest]
+    fn inverts_over_rationals() {
+        let matrix = Matrix::from(vec![vec![2, 0, 1], vec![1, 3, 2], vec![1, 1, 2]]);
+
+        let inverse = matrix.inverse().unwrap();
+
+        assert_eq!(&matrix.to_rational() * &inverse, Matrix::identity(3));
+        assert_eq!(inverse[(0, 0)], Rational::new(2, 3));
+        assert_eq!(Matrix::from(vec![vec![1, 2], vec![2, 4]]).inverse(), None);
+    }
+
+    #[rstest]
+    fn raises_to_power() {
+        let fibonacci = Matrix::from(vec![vec![1, 1], vec![1, 0]]);
+
+        assert_eq!(fibonacci.pow(0), Matrix::identity(2));
+        assert_eq!(fibonacci.pow(1), fibonacci);
+        assert_eq!(fibonacci.pow(30)[(0, 1)], 832040);
+        assert_eq!(fibonacci.pow_mod(30, 1000)[(0, 1)], 40);
+
+        // F(1000) mod 1_000_000_007
+        assert_eq!(fibonacci.pow_mod(1000, 1_000_000_007)[(0, 1)], 517691607);
+    }
+
+    #[rstest]
+    fn supports_row_operations_and_indexing() {
+        let mut matrix = Matrix::from(vec![vec![1, 2], vec![3, 4]]);
+
+        matrix.swap_rows(0, 1);
+        matrix.scale_row(1, 2);
+        matrix.add_row(0, 1, -1);
+        matrix[(1, 1)] = 9;
+
+        assert_eq!(matrix, Matrix::from(vec![vec![1, 0], vec![2, 9]]));
+        assert_eq!(matrix.row(1), &[2, 9]);
+    }
+}
@@ -0,0 +1,317 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Storage behind a memoised function
+pub trait Cache<K, V>: Default {
+    fn lookup(&self, key: &K) -> Option<&V>;
+    fn store(&mut self, key: K, value: V);
+    fn len(&self) -> usize;
+    fn clear(&mut self);
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Hash + Eq, V> Cache<K, V> for HashMap<K, V> {
+    fn lookup(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn store(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+
+    fn clear(&mut self) {
+        HashMap::clear(self)
+    }
+}
+
+/// A cache for small integer keys, stored as a vector that grows to fit the largest key
+#[derive(Debug, Clone)]
+pub struct Dense<V>(Vec<Option<V>>);
+
+impl<V> Default for Dense<V> {
+    fn default() -> Self {
+        Dense(vec![])
+    }
+}
+
+impl<V> Cache<usize, V> for Dense<V> {
+    fn lookup(&self, key: &usize) -> Option<&V> {
+        self.0.get(*key)?.as_ref()
+    }
+
+    fn store(&mut self, key: usize, value: V) {
+        if key >= self.0.len() {
+            self.0.resize_with(key + 1, || None);
+        }
+
+        self.0[key] = Some(value);
+    }
+
+    fn len(&self) -> usize {
+        self.0.iter().flatten().count()
+    }
+
+    fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+/// How well a memoised function has been using its cache since it was last cleared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Number of values currently cached
+    pub entries: usize,
+}
+
+/// A cache that keeps track of its hits and misses
+#[derive(Debug, Clone, Default)]
+pub struct Memo<C> {
+    cache: C,
+    hits: u64,
+    misses: u64,
+}
+
+impl<C> Memo<C> {
+    /// Looks up a cached value, counting a hit or a miss
+    pub fn get<K, V>(&mut self, key: &K) -> Option<V>
+    where
+        C: Cache<K, V>,
+        V: Clone,
+    {
+        let value = self.cache.lookup(key).cloned();
+        match value {
+            Some(_) => self.hits += 1,
+            None => self.misses += 1,
+        }
+
+        value
+    }
+
+    pub fn insert<K, V>(&mut self, key: K, value: V)
+    where
+        C: Cache<K, V>,
+    {
+        self.cache.store(key, value);
+    }
+
+    /// Empties the cache and resets the statistics
+    pub fn clear<K, V>(&mut self)
+    where
+        C: Cache<K, V>,
+    {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    pub fn stats<K, V>(&self) -> Stats
+    where
+        C: Cache<K, V>,
+    {
+        Stats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.cache.len(),
+        }
+    }
+}
+
+/// Wraps a recursive function with a cache, so every key is only computed once. Recursive calls
+/// go through the cache as well
+///
+/// The cache is keyed on a clone of all arguments by default. A `key: expr => Type;` header picks
+/// the key from the arguments instead, useful when some arguments like the grid never change.
+/// An `index: expr;` header uses a dense vector for small usize keys. Keys have to own their data
+/// as the cache outlives the call.
+///
+/// The cache lives per thread and alongside the function a module of the same name is created,
+/// with `stats()` to inspect hits and misses and `clear()` to empty the cache, which is needed
+/// when arguments outside of the key change, like between the example and the real input.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::memoize;
+///
+/// memoize! {
+///     index: n;
+///     fn fibonacci(n: usize) -> u64 {
+///         if n < 2 {
+///             return n as u64;
+///         }
+///
+///         fibonacci(n - 1) + fibonacci(n - 2)
+///     }
+/// }
+///
+/// assert_eq!(fibonacci(90), 2880067194370816120);
+/// assert_eq!(fibonacci::stats().misses, 91);
+///
+/// fibonacci::clear();
+/// assert_eq!(fibonacci::stats().entries, 0);
+/// ```
+#[macro_export]
+macro_rules! memoize {
+    (
+        key: $key:expr => $key_type:ty;
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $arg_type:ty),* $(,)?) -> $ret:ty $body:block
+    ) => {
+        $crate::memoize!(
+            @impl [std::collections::HashMap<$key_type, $ret>] [$key]
+            $(#[$meta])* $vis fn $name($($arg: $arg_type),*) -> $ret $body
+        );
+    };
+    (
+        index: $index:expr;
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $arg_type:ty),* $(,)?) -> $ret:ty $body:block
+    ) => {
+        $crate::memoize!(
+            @impl [$crate::components::memo::Dense<$ret>] [$index]
+            $(#[$meta])* $vis fn $name($($arg: $arg_type),*) -> $ret $body
+        );
+    };
+    (
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $arg_type:ty),* $(,)?) -> $ret:ty $body:block
+    ) => {
+        $crate::memoize!(
+            @impl [std::collections::HashMap<($($arg_type,)*), $ret>] [($($arg.clone(),)*)]
+            $(#[$meta])* $vis fn $name($($arg: $arg_type),*) -> $ret $body
+        );
+    };
+
+    (
+        @impl [$cache:ty] [$key:expr]
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $arg_type:ty),*) -> $ret:ty $body:block
+    ) => {
+        $(#[$meta])*
+        $vis fn $name($($arg: $arg_type),*) -> $ret {
+            let key = $key;
+            if let Some(value) = $name::CACHE.with(|memo| memo.borrow_mut().get(&key)) {
+                return value;
+            }
+
+            // The body runs in a closure so an early return still ends up in the cache
+            #[allow(clippy::redundant_closure_call)]
+            let value: $ret = (|| $body)();
+
+            $name::CACHE.with(|memo| memo.borrow_mut().insert(key, value.clone()));
+            value
+        }
+
+        #[allow(dead_code)]
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            thread_local! {
+                pub(super) static CACHE: std::cell::RefCell<$crate::components::memo::Memo<$cache>> =
+                    std::cell::RefCell::new(Default::default());
+            }
+
+            pub fn stats() -> $crate::components::memo::Stats {
+                CACHE.with(|memo| memo.borrow().stats())
+            }
+
+            pub fn clear() {
+                CACHE.with(|memo| memo.borrow_mut().clear())
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use crate::components::Point;
+
+    memoize! {
+        /// Number of monotone lattice paths from 0,0 to x,y
+        fn lattice_paths(x: u64, y: u64) -> u64 {
+            if x == 0 || y == 0 {
+                return 1;
+            }
+
+            lattice_paths(x - 1, y) + lattice_paths(x, y - 1)
+        }
+    }
+
+    memoize! {
+        key: point => Point;
+        fn weighted_paths(weights: &[isize], point: Point) -> isize {
+            if point.x == 0 && point.y == 0 {
+                return weights[0];
+            }
+
+            let mut total = 0;
+            if point.x > 0 {
+                total += weighted_paths(weights, point + Point::LEFT);
+            }
+            if point.y > 0 {
+                total += weighted_paths(weights, point + Point::UP);
+            }
+
+            total * weights[(point.x + point.y) as usize % weights.len()]
+        }
+    }
+
+    memoize! {
+        index: n;
+        fn collatz_steps(n: usize) -> Option<u64> {
+            if n == 1 {
+                return Some(0);
+            }
+
+            let next = if n.is_multiple_of(2) { n / 2 } else { 3 * n + 1 };
+            Some(collatz_steps(next)? + 1)
+        }
+    }
+
+    #[rstest]
+    fn it_memoises_on_all_arguments() {
+        lattice_paths::clear();
+
+        assert_eq!(lattice_paths(16, 16), 601080390);
+
+        let stats = lattice_paths::stats();
+        assert_eq!(stats.entries, 16 * 16 + 2 * 16);
+        assert_eq!(stats.misses, stats.entries as u64);
+        assert!(stats.hits > 0);
+    }
+
+    #[rstest]
+    fn it_memoises_on_a_key() {
+        weighted_paths::clear();
+
+        assert_eq!(weighted_paths(&[1], Point::new(3, 3)), 20);
+        assert_eq!(weighted_paths(&[2], Point::new(3, 3)), 20);
+        assert_eq!(weighted_paths::stats().hits, 9 + 1);
+
+        weighted_paths::clear();
+        assert_eq!(weighted_paths::stats(), Default::default());
+        assert_eq!(weighted_paths(&[2], Point::new(1, 1)), 16);
+    }
+
+    #[rstest]
+    fn it_memoises_early_returns_in_dense_caches() {
+        collatz_steps::clear();
+
+        assert_eq!(collatz_steps(27), Some(111));
+        assert_eq!(collatz_steps(54), Some(112));
+
+        let stats = collatz_steps::stats();
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.entries, 113);
+    }
+}
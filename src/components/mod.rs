@@ -1,3 +1,15 @@
+pub mod exact_cover;
+pub mod export;
+pub mod graph;
+pub mod ilp;
+pub mod interval;
 pub mod matrix;
+pub mod memo;
 mod point;
+mod point3;
+pub mod polygon;
+pub mod rational;
+pub mod shapes;
+pub mod union_find;
 pub use point::Point;
+pub use point3::Point3;
@@ -186,6 +186,20 @@ impl Point {
         (distance.x.abs() + distance.y.abs()) as usize
     }
 
+    /// Calculates the chebyshev distance between two points, the number of steps when diagonal
+    /// moves are allowed
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::components::Point;
+    /// assert_eq!(Point::new(0,0).chebyshev_distance(&Point::new(3, 5)), 5);
+    /// assert_eq!(Point::new(-2,1).chebyshev_distance(&Point::new(2, 0)), 4);
+    /// ```
+    pub fn chebyshev_distance(&self, rhs: &Point) -> usize {
+        let distance = (*self - *rhs).abs();
+        distance.x.max(distance.y) as usize
+    }
+
     pub fn abs(&self) -> Point {
         Point::new(self.x.abs(), self.y.abs())
     }
@@ -267,7 +281,7 @@ mod test {
     #[case("4,-a5", ",", "-a5: invalid digit found in string")]
     #[case("b4,-a5", ",", "b4: invalid digit found in string")]
     fn it_returns_an(#[case] input: &str, #[case] seperator: &str, #[case] result: String) {
-        assert_eq!(Point::parse_seperated(input, seperator), Err(result.into()))
+        assert_eq!(Point::parse_seperated(input, seperator), Err(result))
     }
 
     #[rstest]
@@ -0,0 +1,260 @@
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Sub, SubAssign},
+};
+
+use itertools::Itertools;
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Point3 {
+    pub const RIGHT: Point3 = Point3 { x: 1, y: 0, z: 0 };
+    pub const LEFT: Point3 = Point3 { x: -1, y: 0, z: 0 };
+    pub const DOWN: Point3 = Point3 { x: 0, y: 1, z: 0 };
+    pub const UP: Point3 = Point3 { x: 0, y: -1, z: 0 };
+    pub const FORWARD: Point3 = Point3 { x: 0, y: 0, z: 1 };
+    pub const BACKWARD: Point3 = Point3 { x: 0, y: 0, z: -1 };
+
+    pub const DIRECTIONS_6: [Point3; 6] = [
+        Self::RIGHT,
+        Self::LEFT,
+        Self::DOWN,
+        Self::UP,
+        Self::FORWARD,
+        Self::BACKWARD,
+    ];
+
+    pub fn new(x: isize, y: isize, z: isize) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    /// Parses a Point3 seperated by a seperator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use advent_of_code::components::Point3;
+    /// assert_eq!(Point3::parse_seperated("4,-5,6", ",").unwrap(), Point3::new(4, -5, 6));
+    /// ```
+    pub fn parse_seperated(input: &str, seperator: &str) -> Result<Point3, String> {
+        let Some((x, y, z)) = input.split(seperator).collect_tuple() else {
+            return Err(format!(
+                "{}: expected 3 values seperated by [{}]",
+                input, seperator
+            ));
+        };
+
+        Ok(Point3 {
+            x: x.parse::<isize>().map_err(|e| format!("{}: {}", x, e))?,
+            y: y.parse::<isize>().map_err(|e| format!("{}: {}", y, e))?,
+            z: z.parse::<isize>().map_err(|e| format!("{}: {}", z, e))?,
+        })
+    }
+
+    /// Returns the 6 neighbours sharing a face with the point
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::components::Point3;
+    /// assert!(Point3::new(1, 1, 1).neighbours().contains(&Point3::new(1, 1, 0)));
+    /// assert_eq!(Point3::new(1, 1, 1).neighbours().len(), 6);
+    /// ```
+    pub fn neighbours(&self) -> Vec<Point3> {
+        Self::DIRECTIONS_6.iter().map(|d| *self + *d).collect()
+    }
+
+    /// Returns all 26 neighbours sharing a face, edge or corner with the point
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::components::Point3;
+    /// assert!(Point3::new(1, 1, 1).full_neighbours().contains(&Point3::new(0, 2, 0)));
+    /// assert_eq!(Point3::new(1, 1, 1).full_neighbours().len(), 26);
+    /// ```
+    pub fn full_neighbours(&self) -> Vec<Point3> {
+        (-1..=1)
+            .cartesian_product(-1..=1)
+            .cartesian_product(-1..=1)
+            .map(|((x, y), z)| Point3::new(x, y, z))
+            .filter(|d| *d != Point3::new(0, 0, 0))
+            .map(|d| *self + d)
+            .collect()
+    }
+
+    /// Calculates the manhattan distance of a point to another point
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::components::Point3;
+    /// assert_eq!(Point3::new(1, 2, 3).distance(&Point3::new(-1, 2, 6)), 5);
+    /// ```
+    pub fn distance(&self, rhs: &Point3) -> usize {
+        let distance = (*self - *rhs).abs();
+        (distance.x + distance.y + distance.z) as usize
+    }
+
+    /// Calculates the squared euclidean distance of a point to another point. Avoids floating
+    /// point math, and sorts the same as the actual euclidean distance
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::components::Point3;
+    /// assert_eq!(Point3::new(1, 2, 3).squared_distance(&Point3::new(-1, 2, 6)), 13);
+    /// ```
+    pub fn squared_distance(&self, rhs: &Point3) -> usize {
+        let distance = *self - *rhs;
+        (distance.x.pow(2) + distance.y.pow(2) + distance.z.pow(2)) as usize
+    }
+
+    /// Calculates the euclidean distance of a point to another point
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::components::Point3;
+    /// assert_eq!(Point3::new(0, 0, 0).euclidean_distance(&Point3::new(2, 3, 6)), 7.0);
+    /// ```
+    pub fn euclidean_distance(&self, rhs: &Point3) -> f64 {
+        (self.squared_distance(rhs) as f64).sqrt()
+    }
+
+    pub fn abs(&self) -> Point3 {
+        Point3::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+}
+
+impl AddAssign<Point3> for Point3 {
+    fn add_assign(&mut self, rhs: Point3) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point3 {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point3 {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl Mul<isize> for Point3 {
+    type Output = Point3;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Point3 {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("4,-5,6", ",", Point3::new(4, -5, 6))]
+    #[case("4 -5 6", " ", Point3::new(4, -5, 6))]
+    #[case("0,0,0", ",", Point3::new(0, 0, 0))]
+    #[case("162,817,812", ",", Point3::new(162, 817, 812))]
+    fn it_can_parse_seperated(
+        #[case] input: &str,
+        #[case] seperator: &str,
+        #[case] result: Point3,
+    ) {
+        assert_eq!(Point3::parse_seperated(input, seperator).unwrap(), result)
+    }
+
+    #[rstest]
+    #[case("4,-5", ",", "4,-5: expected 3 values seperated by [,]")]
+    #[case("4,-5,6,7", ",", "4,-5,6,7: expected 3 values seperated by [,]")]
+    #[case("4,-5,a6", ",", "a6: invalid digit found in string")]
+    fn it_returns_an_error(#[case] input: &str, #[case] seperator: &str, #[case] result: String) {
+        assert_eq!(Point3::parse_seperated(input, seperator), Err(result))
+    }
+
+    #[rstest]
+    #[case(Point3::new(1, 1, 1), Point3::new(2, 2, 2), Point3::new(3, 3, 3))]
+    #[case(Point3::new(-2, 1, 0), Point3::new(2, 2, -4), Point3::new(0, 3, -4))]
+    fn it_can_add(#[case] mut input: Point3, #[case] rhs: Point3, #[case] result: Point3) {
+        assert_eq!(input + rhs, result);
+        // Using add assign should also work
+        input += rhs;
+        assert_eq!(input, result);
+    }
+
+    #[rstest]
+    #[case(Point3::new(3, 3, 3), Point3::new(2, 2, 2), Point3::new(1, 1, 1))]
+    #[case(Point3::new(0, 3, -4), Point3::new(2, 2, -4), Point3::new(-2, 1, 0))]
+    fn it_can_sub(#[case] mut input: Point3, #[case] rhs: Point3, #[case] result: Point3) {
+        assert_eq!(input - rhs, result);
+        input -= rhs;
+        assert_eq!(input, result);
+    }
+
+    #[rstest]
+    fn neighbours_are_unique_and_adjacent() {
+        let point = Point3::new(5, -5, 0);
+
+        let neighbours = point.neighbours();
+        assert_eq!(neighbours.iter().unique().count(), 6);
+        assert!(neighbours.iter().all(|n| n.distance(&point) == 1));
+
+        let full_neighbours = point.full_neighbours();
+        assert_eq!(full_neighbours.iter().unique().count(), 26);
+        assert!(!full_neighbours.contains(&point));
+        assert!(neighbours.iter().all(|n| full_neighbours.contains(n)));
+        assert!(
+            full_neighbours
+                .iter()
+                .all(|n| (n.x - point.x).abs() <= 1 && (n.y - point.y).abs() <= 1)
+        );
+    }
+
+    #[rstest]
+    #[case(Point3::new(162, 817, 812), Point3::new(425, 690, 689), 100_427)]
+    #[case(Point3::new(0, 0, 0), Point3::new(-1, -1, -1), 3)]
+    fn it_calculates_squared_distance(#[case] a: Point3, #[case] b: Point3, #[case] result: usize) {
+        assert_eq!(a.squared_distance(&b), result);
+        assert_eq!(b.squared_distance(&a), result);
+    }
+}
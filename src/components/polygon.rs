@@ -0,0 +1,465 @@
+use super::{
+    Point,
+    rational::{Rational, gcd},
+};
+
+fn cross(a: Point, b: Point) -> isize {
+    a.x * b.y - a.y * b.x
+}
+
+fn dot(a: Point, b: Point) -> isize {
+    a.x * b.x + a.y * b.y
+}
+
+/// A straight line between two points, including both ends
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Segment {
+    pub fn new(start: Point, end: Point) -> Segment {
+        Segment { start, end }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    /// Number of lattice points on the segment, including both ends
+    pub fn lattice_points(&self) -> usize {
+        let delta = self.end - self.start;
+
+        gcd(delta.x, delta.y) as usize + 1
+    }
+
+    /// Check if a point lies on the segment
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::components::{Point, polygon::Segment};
+    ///
+    /// let segment = Segment::new(Point::new(0, 0), Point::new(4, 2));
+    /// assert!(segment.contains(&Point::new(2, 1)));
+    /// assert!(!segment.contains(&Point::new(2, 2)));
+    /// ```
+    pub fn contains(&self, point: &Point) -> bool {
+        cross(self.end - self.start, *point - self.start) == 0
+            && point.x >= self.start.x.min(self.end.x)
+            && point.x <= self.start.x.max(self.end.x)
+            && point.y >= self.start.y.min(self.end.y)
+            && point.y <= self.start.y.max(self.end.y)
+    }
+
+    /// Check if two segments share at least one point, touching ends and overlaps included
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::components::{Point, polygon::Segment};
+    ///
+    /// let a = Segment::new(Point::new(0, 0), Point::new(4, 4));
+    /// assert!(a.intersects(&Segment::new(Point::new(0, 4), Point::new(4, 0))));
+    /// assert!(a.intersects(&Segment::new(Point::new(4, 4), Point::new(6, 4))));
+    /// assert!(!a.intersects(&Segment::new(Point::new(1, 0), Point::new(5, 4))));
+    /// ```
+    pub fn intersects(&self, other: &Segment) -> bool {
+        let d = self.end - self.start;
+        let e = other.end - other.start;
+
+        let a = cross(d, other.start - self.start).signum();
+        let b = cross(d, other.end - self.start).signum();
+        let c = cross(e, self.start - other.start).signum();
+        let f = cross(e, self.end - other.start).signum();
+
+        if a * b < 0 && c * f < 0 {
+            return true;
+        }
+
+        self.contains(&other.start)
+            || self.contains(&other.end)
+            || other.contains(&self.start)
+            || other.contains(&self.end)
+    }
+
+    /// Check if the segment passes through the open rectangle strictly between lower and upper
+    fn enters(&self, lower: Point, upper: Point) -> bool {
+        if self.start.x.min(self.end.x) >= upper.x
+            || self.start.x.max(self.end.x) <= lower.x
+            || self.start.y.min(self.end.y) >= upper.y
+            || self.start.y.max(self.end.y) <= lower.y
+        {
+            return false;
+        }
+
+        let d = self.end - self.start;
+        let sides = [
+            lower,
+            upper,
+            Point::new(lower.x, upper.y),
+            Point::new(upper.x, lower.y),
+        ]
+        .map(|corner| cross(d, corner - self.start).signum());
+
+        sides.contains(&1) && sides.contains(&-1)
+    }
+
+    /// Positions along self, as a fraction of its length, where it meets other. Overlapping
+    /// segments return the ends of the overlap, which may lie outside of 0..=1
+    fn meets(&self, other: &Segment) -> Vec<Rational> {
+        let d = self.end - self.start;
+        let e = other.end - other.start;
+        let offset = other.start - self.start;
+
+        let denom = cross(d, e);
+        if denom != 0 {
+            let t = Rational::new(cross(offset, e), denom);
+            let u = Rational::new(cross(offset, d), denom);
+            let range = Rational::ZERO..=Rational::ONE;
+
+            return if range.contains(&t) && range.contains(&u) {
+                vec![t]
+            } else {
+                vec![]
+            };
+        }
+
+        if cross(offset, d) != 0 {
+            return vec![];
+        }
+
+        let length = dot(d, d);
+        vec![
+            Rational::new(dot(offset, d), length),
+            Rational::new(dot(other.end - self.start, d), length),
+        ]
+    }
+}
+
+/// Where a point lies relative to a polygon
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// A simple polygon on integer coordinates, given by its vertices in order. The last vertex
+/// connects back to the first
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::{Point, polygon::Polygon};
+///
+/// let polygon = Polygon::new(vec![
+///     Point::new(0, 0),
+///     Point::new(4, 0),
+///     Point::new(4, 3),
+///     Point::new(0, 3),
+/// ]);
+///
+/// assert_eq!(polygon.double_area(), 24);
+/// assert_eq!(polygon.boundary_points(), 14);
+/// assert_eq!(polygon.interior_points(), 6);
+/// assert!(polygon.contains_rectangle(&Point::new(1, 0), &Point::new(4, 2)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// Panics with less than 3 vertices
+    pub fn new(vertices: Vec<Point>) -> Polygon {
+        assert!(vertices.len() >= 3, "a polygon needs at least 3 vertices");
+
+        Polygon { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = Segment> + '_ {
+        self.vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(start, end)| Segment::new(*start, *end))
+    }
+
+    /// Twice the enclosed area using the shoelace formula, which is always an integer
+    pub fn double_area(&self) -> isize {
+        self.edges()
+            .map(|edge| cross(edge.start, edge.end))
+            .sum::<isize>()
+            .abs()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.double_area() as f64 / 2.0
+    }
+
+    /// Number of lattice points on the edges
+    pub fn boundary_points(&self) -> usize {
+        self.edges().map(|edge| edge.lattice_points() - 1).sum()
+    }
+
+    /// Number of lattice points strictly inside, using Pick's theorem
+    /// Degenerate polygons without area, such as collinear vertices, have no interior and return 0
+    pub fn interior_points(&self) -> usize {
+        (self.double_area().unsigned_abs() + 2).saturating_sub(self.boundary_points()) / 2
+    }
+
+    /// Number of lattice points inside or on the boundary
+    pub fn lattice_points(&self) -> usize {
+        self.interior_points() + self.boundary_points()
+    }
+
+    /// Locates a point by casting a ray to the right and counting the edges it crosses
+    pub fn locate(&self, point: &Point) -> Location {
+        self.locate_exact(Rational::from(point.x), Rational::from(point.y))
+    }
+
+    /// Check if a point lies inside or on the boundary
+    pub fn contains(&self, point: &Point) -> bool {
+        self.locate(point) != Location::Outside
+    }
+
+    fn locate_exact(&self, x: Rational, y: Rational) -> Location {
+        let mut inside = false;
+
+        for edge in self.edges() {
+            let (ax, ay) = (Rational::from(edge.start.x), Rational::from(edge.start.y));
+            let (bx, by) = (Rational::from(edge.end.x), Rational::from(edge.end.y));
+
+            if (bx - ax) * (y - ay) == (by - ay) * (x - ax)
+                && x >= ax.min(bx)
+                && x <= ax.max(bx)
+                && y >= ay.min(by)
+                && y <= ay.max(by)
+            {
+                return Location::Boundary;
+            }
+
+            // Half open on y, so a ray through a vertex counts the crossing only once
+            if (ay > y) != (by > y) && x < ax + (y - ay) * (bx - ax) / (by - ay) {
+                inside = !inside;
+            }
+        }
+
+        if inside {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    /// Check if the whole segment lies inside or on the boundary, by testing the middle of every
+    /// piece between the points where it meets an edge
+    pub fn contains_segment(&self, segment: &Segment) -> bool {
+        if !self.contains(&segment.start) || !self.contains(&segment.end) {
+            return false;
+        }
+
+        let mut positions = vec![Rational::ZERO, Rational::ONE];
+        if segment.start != segment.end {
+            positions.extend(self.edges().flat_map(|edge| segment.meets(&edge)));
+        }
+
+        positions.retain(|t| (Rational::ZERO..=Rational::ONE).contains(t));
+        positions.sort();
+        positions.dedup();
+
+        let d = segment.end - segment.start;
+        positions.windows(2).all(|pair| {
+            let t = (pair[0] + pair[1]) / Rational::from(2);
+
+            self.locate_exact(
+                Rational::from(segment.start.x) + t * Rational::from(d.x),
+                Rational::from(segment.start.y) + t * Rational::from(d.y),
+            ) != Location::Outside
+        })
+    }
+
+    /// Check if the axis-aligned rectangle with opposite corners a and b lies inside or on the
+    /// boundary
+    ///
+    /// A rectangle fits when no edge passes through its interior and its center is inside, so
+    /// corners touching the boundary and notches between them are handled
+    pub fn contains_rectangle(&self, a: &Point, b: &Point) -> bool {
+        let lower = Point::new(a.x.min(b.x), a.y.min(b.y));
+        let upper = Point::new(a.x.max(b.x), a.y.max(b.y));
+
+        if lower.x == upper.x || lower.y == upper.y {
+            return self.contains_segment(&Segment::new(lower, upper));
+        }
+
+        if self.edges().any(|edge| edge.enters(lower, upper)) {
+            return false;
+        }
+
+        self.locate_exact(
+            Rational::new(lower.x + upper.x, 2),
+            Rational::new(lower.y + upper.y, 2),
+        ) == Location::Inside
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn polygon(points: &[(isize, isize)]) -> Polygon {
+        Polygon::new(points.iter().map(|(x, y)| Point::new(*x, *y)).collect())
+    }
+
+    fn polygon_reversed(points: &[(isize, isize)]) -> Polygon {
+        polygon(&points.iter().rev().copied().collect::<Vec<_>>())
+    }
+
+    /// A square with a notch cut from the top edge down to y = 1
+    fn notched() -> Polygon {
+        polygon(&[
+            (0, 0),
+            (4, 0),
+            (4, 4),
+            (3, 4),
+            (3, 1),
+            (1, 1),
+            (1, 4),
+            (0, 4),
+        ])
+    }
+
+    /// The example from day 09
+    fn tiles() -> Polygon {
+        polygon(&[
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ])
+    }
+
+    #[rstest]
+    fn it_counts_lattice_points() {
+        let polygon = notched();
+
+        assert_eq!(polygon.double_area(), 20);
+        assert_eq!(polygon.area(), 10.0);
+        assert_eq!(polygon.boundary_points(), 22);
+        assert_eq!(polygon.interior_points(), 0);
+        assert_eq!(polygon.lattice_points(), 22);
+
+        let triangle = polygon_reversed(&[(0, 0), (4, 0), (0, 4)]);
+        assert_eq!(triangle.double_area(), 16);
+        assert_eq!(triangle.boundary_points(), 12);
+        assert_eq!(triangle.interior_points(), 3);
+    }
+
+    #[rstest]
+    fn it_has_no_interior_without_area() {
+        let line = polygon_reversed(&[(0, 0), (2, 0), (4, 0)]);
+
+        assert_eq!(line.double_area(), 0);
+        assert_eq!(line.boundary_points(), 8);
+        assert_eq!(line.interior_points(), 0);
+    }
+
+    #[rstest]
+    #[case(Point::new(2, 0), Location::Boundary)]
+    #[case(Point::new(2, 1), Location::Boundary)]
+    #[case(Point::new(2, 2), Location::Outside)]
+    #[case(Point::new(0, 4), Location::Boundary)]
+    #[case(Point::new(3, 2), Location::Boundary)]
+    #[case(Point::new(-1, 1), Location::Outside)]
+    #[case(Point::new(5, 4), Location::Outside)]
+    fn it_locates_points(#[case] point: Point, #[case] expected: Location) {
+        assert_eq!(notched().locate(&point), expected);
+    }
+
+    #[rstest]
+    fn it_locates_points_level_with_vertices() {
+        let polygon = tiles();
+
+        assert_eq!(polygon.locate(&Point::new(5, 3)), Location::Boundary);
+        assert_eq!(polygon.locate(&Point::new(8, 3)), Location::Inside);
+        assert_eq!(polygon.locate(&Point::new(8, 4)), Location::Inside);
+        assert_eq!(polygon.locate(&Point::new(10, 6)), Location::Inside);
+        assert_eq!(polygon.locate(&Point::new(8, 6)), Location::Outside);
+        assert_eq!(polygon.locate(&Point::new(1, 5)), Location::Outside);
+        assert_eq!(polygon.locate(&Point::new(6, 1)), Location::Outside);
+    }
+
+    #[rstest]
+    #[case((0, 0), (4, 1), true)]
+    #[case((0, 0), (1, 4), true)]
+    #[case((0, 0), (4, 2), false)]
+    #[case((1, 1), (3, 4), false)]
+    #[case((0, 1), (4, 1), true)]
+    #[case((0, 2), (4, 2), false)]
+    #[case((3, 3), (3, 3), true)]
+    #[case((2, 3), (2, 3), false)]
+    fn it_checks_rectangles(
+        #[case] a: (isize, isize),
+        #[case] b: (isize, isize),
+        #[case] expected: bool,
+    ) {
+        let (a, b) = (Point::new(a.0, a.1), Point::new(b.0, b.1));
+
+        assert_eq!(notched().contains_rectangle(&a, &b), expected);
+        assert_eq!(notched().contains_rectangle(&b, &a), expected);
+    }
+
+    #[rstest]
+    fn it_checks_rectangles_of_day_09() {
+        let polygon = tiles();
+
+        assert!(polygon.contains_rectangle(&Point::new(9, 5), &Point::new(2, 3)));
+        assert!(polygon.contains_rectangle(&Point::new(7, 3), &Point::new(11, 1)));
+        assert!(!polygon.contains_rectangle(&Point::new(2, 5), &Point::new(11, 1)));
+        assert!(!polygon.contains_rectangle(&Point::new(2, 3), &Point::new(11, 7)));
+    }
+
+    #[rstest]
+    fn it_intersects_segments() {
+        let a = Segment::new(Point::new(0, 0), Point::new(0, 4));
+
+        assert!(a.intersects(&Segment::new(Point::new(-2, 2), Point::new(2, 2))));
+        assert!(a.intersects(&Segment::new(Point::new(0, 4), Point::new(0, 8))));
+        assert!(a.intersects(&Segment::new(Point::new(0, 2), Point::new(3, 2))));
+        assert!(!a.intersects(&Segment::new(Point::new(0, 5), Point::new(0, 8))));
+        assert!(!a.intersects(&Segment::new(Point::new(1, 0), Point::new(1, 4))));
+        assert!(!a.intersects(&Segment::new(Point::new(-2, 6), Point::new(2, 5))));
+    }
+
+    #[rstest]
+    fn it_checks_segments_against_notches() {
+        let polygon = notched();
+
+        let across = Segment::new(Point::new(0, 3), Point::new(4, 3));
+        assert!(!polygon.contains_segment(&across));
+
+        let diagonal = Segment::new(Point::new(0, 0), Point::new(1, 4));
+        assert!(polygon.contains_segment(&diagonal));
+
+        let corners = Segment::new(Point::new(1, 4), Point::new(3, 4));
+        assert!(!polygon.contains_segment(&corners));
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn new_should_panic_on_too_few_vertices() {
+        polygon(&[(0, 0), (1, 1)]);
+    }
+}
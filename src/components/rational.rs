@@ -0,0 +1,243 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// Greatest common divisor, always non-negative. gcd(0, 0) is 0
+pub fn gcd(a: isize, b: isize) -> isize {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// An exact fraction, always stored in lowest terms with a positive denominator
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::rational::Rational;
+///
+/// let a = Rational::new(1, 3);
+/// let b = Rational::new(-4, 6);
+///
+/// assert_eq!(a + b, Rational::new(-1, 3));
+/// assert_eq!(a * Rational::from(6), Rational::from(2));
+/// assert_eq!((a / b).to_string(), "-1/2");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: isize,
+    denom: isize,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numer: 0, denom: 1 };
+    pub const ONE: Rational = Rational { numer: 1, denom: 1 };
+
+    /// Creates the fraction numer/denom, reduced to lowest terms
+    /// Panics if denom is zero
+    pub fn new(numer: isize, denom: isize) -> Rational {
+        assert!(denom != 0, "denominator cannot be zero");
+
+        let divisor = gcd(numer, denom) * denom.signum();
+
+        Rational {
+            numer: numer / divisor,
+            denom: denom / divisor,
+        }
+    }
+
+    pub fn numer(&self) -> isize {
+        self.numer
+    }
+
+    pub fn denom(&self) -> isize {
+        self.denom
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numer == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom == 1
+    }
+
+    /// Returns the value as an integer, None if it has a fractional part
+    pub fn to_integer(&self) -> Option<isize> {
+        self.is_integer().then_some(self.numer)
+    }
+
+    pub fn abs(&self) -> Rational {
+        Rational {
+            numer: self.numer.abs(),
+            denom: self.denom,
+        }
+    }
+
+    /// Returns 1/self
+    /// Panics if self is zero
+    pub fn recip(&self) -> Rational {
+        Rational::new(self.denom, self.numer)
+    }
+}
+
+impl From<isize> for Rational {
+    fn from(value: isize) -> Self {
+        Rational {
+            numer: value,
+            denom: 1,
+        }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let divisor = gcd(self.denom, rhs.denom);
+        let denom = self.denom / divisor * rhs.denom;
+
+        Rational::new(
+            self.numer * (denom / self.denom) + rhs.numer * (denom / rhs.denom),
+            denom,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Cross reduce first to keep the intermediate products small
+        let a = gcd(self.numer, rhs.denom).max(1);
+        let b = gcd(rhs.numer, self.denom).max(1);
+
+        Rational::new(
+            (self.numer / a) * (rhs.numer / b),
+            (self.denom / b) * (rhs.denom / a),
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Mul::mul(self, rhs.recip())
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Self::Output {
+        Rational {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = self.numer as i128 * other.denom as i128;
+        let rhs = other.numer as i128 * self.denom as i128;
+
+        lhs.cmp(&rhs)
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(2, 4, 1, 2)]
+    #[case(-2, 4, -1, 2)]
+    #[case(2, -4, -1, 2)]
+    #[case(-3, -9, 1, 3)]
+    #[case(0, -5, 0, 1)]
+    fn it_normalizes(
+        #[case] numer: isize,
+        #[case] denom: isize,
+        #[case] expected_numer: isize,
+        #[case] expected_denom: isize,
+    ) {
+        let value = Rational::new(numer, denom);
+
+        assert_eq!(value.numer(), expected_numer);
+        assert_eq!(value.denom(), expected_denom);
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn new_should_panic_on_zero_denominator() {
+        Rational::new(1, 0);
+    }
+
+    #[rstest]
+    fn it_does_arithmetic() {
+        let half = Rational::new(1, 2);
+        let third = Rational::new(1, 3);
+
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(half / third, Rational::new(3, 2));
+        assert_eq!(-half, Rational::new(-1, 2));
+        assert_eq!((half + half).to_integer(), Some(1));
+        assert_eq!(half.to_integer(), None);
+        assert_eq!(Rational::new(-2, 3).recip(), Rational::new(-3, 2));
+    }
+
+    #[rstest]
+    fn it_orders_by_value() {
+        let mut values = vec![
+            Rational::new(1, 2),
+            Rational::new(-1, 3),
+            Rational::from(2),
+            Rational::new(1, 3),
+        ];
+        values.sort();
+
+        assert_eq!(
+            values,
+            vec![
+                Rational::new(-1, 3),
+                Rational::new(1, 3),
+                Rational::new(1, 2),
+                Rational::from(2)
+            ]
+        );
+    }
+}
@@ -0,0 +1,436 @@
+use std::{collections::HashSet, fmt::Display};
+
+use itertools::Itertools;
+
+use crate::grid::{Grid, char_grid::CharGrid, transform::Transform};
+
+use super::Point;
+
+/// A set of filled cells, normalized so the top left corner of its bounding box is at 0,0
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Shape {
+    /// Filled cells in reading order
+    cells: Vec<Point>,
+    width: usize,
+    height: usize,
+}
+
+impl Shape {
+    /// Creates a shape from its filled cells, moving it so its bounding box starts at 0,0
+    /// Panics if there are no cells
+    pub fn from_cells(cells: impl IntoIterator<Item = Point>) -> Shape {
+        let cells = cells.into_iter().collect_vec();
+        assert!(!cells.is_empty(), "a shape needs at least one cell");
+
+        let min_x = cells.iter().map(|p| p.x).min().unwrap();
+        let min_y = cells.iter().map(|p| p.y).min().unwrap();
+        let offset = Point::new(min_x, min_y);
+
+        let cells = cells
+            .into_iter()
+            .map(|p| p - offset)
+            .sorted_by_key(|p| (p.y, p.x))
+            .dedup()
+            .collect_vec();
+
+        Shape {
+            width: cells.iter().map(|p| p.x).max().unwrap() as usize + 1,
+            height: cells.iter().map(|p| p.y).max().unwrap() as usize + 1,
+            cells,
+        }
+    }
+
+    /// Parses a block of `#` (filled) and `.` (empty) characters
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::components::shapes::Shape;
+    ///
+    /// let shape = Shape::parse("###\n#..").unwrap();
+    /// assert_eq!(shape.area(), 4);
+    /// assert_eq!(shape.orientations().len(), 8);
+    ///
+    /// assert!(Shape::parse("#x#").is_err());
+    /// ```
+    pub fn parse(input: &str) -> Result<Shape, String> {
+        let grid = CharGrid::try_new(input)?;
+
+        if let Some((point, c)) = grid.entries().find(|(_, c)| *c != '#' && *c != '.') {
+            return Err(format!("unexpected character '{c}' at {point}"));
+        }
+
+        Self::try_from_chargrid(&grid)
+    }
+
+    fn try_from_chargrid(grid: &CharGrid) -> Result<Shape, String> {
+        let cells = grid
+            .entries()
+            .filter(|(_, c)| *c == '#')
+            .map(|(p, _)| p)
+            .collect_vec();
+
+        if cells.is_empty() {
+            return Err("a shape needs at least one cell".to_string());
+        }
+
+        Ok(Self::from_cells(cells))
+    }
+
+    /// Filled cells in reading order
+    pub fn cells(&self) -> &[Point] {
+        &self.cells
+    }
+
+    /// Number of filled cells
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// All unique rotations and reflections of the shape, starting with the shape itself
+    pub fn orientations(&self) -> Vec<Shape> {
+        let grid = CharGrid::new(&self.to_string());
+
+        grid.orientations()
+            .iter()
+            .map(|g| Self::try_from_chargrid(g).unwrap())
+            .unique()
+            .collect()
+    }
+}
+
+impl Display for Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut rows = (0..self.height as isize).map(|y| {
+            (0..self.width as isize)
+                .map(|x| match self.cells.contains(&Point::new(x, y)) {
+                    true => '#',
+                    false => '.',
+                })
+                .collect::<String>()
+        });
+
+        write!(f, "{}", rows.join("\n"))
+    }
+}
+
+/// A copy of a shape put into a region
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placement {
+    /// Index of the shape in the list passed to `pack`
+    pub shape: usize,
+    /// The cells of the region covered by the shape
+    pub cells: Vec<Point>,
+}
+
+/// Tries to fit `counts[i]` copies of `shapes[i]` into a width by height region, rotating and
+/// reflecting them as needed. Shapes cannot overlap, but cells may be left empty. Returns where
+/// every copy was placed, None if they do not fit.
+///
+/// Cells are filled in reading order with backtracking, either by a shape whose first cell lands
+/// on it or by leaving it empty. A branch is abandoned as soon as the cells the remaining shapes
+/// could still cover are too few to hold them, and states that failed before are remembered.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::shapes::{Shape, pack};
+///
+/// let corner = Shape::parse("##\n#.").unwrap();
+///
+/// assert!(pack(&[corner.clone()], &[2], 3, 2).is_some());
+/// assert!(pack(&[corner], &[3], 3, 2).is_none());
+/// ```
+pub fn pack(
+    shapes: &[Shape],
+    counts: &[usize],
+    width: usize,
+    height: usize,
+) -> Option<Vec<Placement>> {
+    assert_eq!(shapes.len(), counts.len(), "every shape needs a count");
+
+    // Every orientation is tried, so a transposed region gives the same answer. Scanning along the
+    // short side keeps the frontier of undecided cells small, making failed states repeat more
+    if width > height {
+        let mut placements = pack(shapes, counts, height, width)?;
+        placements
+            .iter_mut()
+            .flat_map(|p| p.cells.iter_mut())
+            .for_each(|p| *p = Point::new(p.y, p.x));
+
+        return Some(placements);
+    }
+
+    let size = width * height;
+    let mut candidates = (0..size).map(|_| vec![]).collect_vec();
+
+    for (shape, orientation) in shapes
+        .iter()
+        .enumerate()
+        .flat_map(|(i, s)| s.orientations().into_iter().map(move |o| (i, o)))
+    {
+        for (index, anchored) in candidates.iter_mut().enumerate() {
+            let anchor = Point::new((index % width) as isize, (index / width) as isize);
+            let offset = anchor - orientation.cells[0];
+
+            let points = orientation.cells.iter().map(|c| *c + offset).collect_vec();
+            if !points
+                .iter()
+                .all(|p| p.x >= 0 && p.x < width as isize && p.y < height as isize)
+            {
+                continue;
+            }
+
+            let mut cells = Cells::new(size);
+            points
+                .iter()
+                .for_each(|p| cells.insert(p.y as usize * width + p.x as usize));
+
+            anchored.push(Candidate {
+                shape,
+                cells,
+                points,
+            });
+        }
+    }
+
+    let mut packer = Packer {
+        candidates,
+        remaining: counts.to_vec(),
+        areas: shapes.iter().map(|s| s.area()).collect(),
+        required: shapes.iter().zip(counts).map(|(s, c)| s.area() * c).sum(),
+        occupied: Cells::new(size),
+        failed: HashSet::new(),
+        placements: vec![],
+    };
+
+    packer.search(0).then_some(packer.placements)
+}
+
+/// A fixed size set of cell indices
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Cells(Vec<u64>);
+
+impl Cells {
+    fn new(size: usize) -> Cells {
+        Cells(vec![0; size.div_ceil(64)])
+    }
+
+    fn contains(&self, index: usize) -> bool {
+        self.0[index / 64] >> (index % 64) & 1 == 1
+    }
+
+    fn insert(&mut self, index: usize) {
+        self.0[index / 64] |= 1 << (index % 64);
+    }
+
+    fn remove(&mut self, index: usize) {
+        self.0[index / 64] &= !(1 << (index % 64));
+    }
+
+    fn intersects(&self, other: &Cells) -> bool {
+        self.0.iter().zip(&other.0).any(|(a, b)| a & b != 0)
+    }
+
+    fn union(&mut self, other: &Cells) {
+        self.0.iter_mut().zip(&other.0).for_each(|(a, b)| *a |= b);
+    }
+
+    /// Adds cells that are missing and removes cells that are present
+    fn toggle(&mut self, other: &Cells) {
+        self.0.iter_mut().zip(&other.0).for_each(|(a, b)| *a ^= b);
+    }
+
+    fn len(&self) -> usize {
+        self.0.iter().map(|w| w.count_ones() as usize).sum()
+    }
+}
+
+/// One way to place a shape in the region
+struct Candidate {
+    shape: usize,
+    cells: Cells,
+    points: Vec<Point>,
+}
+
+struct Packer {
+    /// Every candidate, grouped by the index of the cell its first cell lands on
+    candidates: Vec<Vec<Candidate>>,
+    remaining: Vec<usize>,
+    areas: Vec<usize>,
+    /// Total area of the remaining shapes
+    required: usize,
+    /// Cells that are covered by a shape or deliberately left empty
+    occupied: Cells,
+    /// States that are known not to lead to a packing
+    failed: HashSet<(Cells, Vec<usize>)>,
+    placements: Vec<Placement>,
+}
+
+impl Packer {
+    fn search(&mut self, mut index: usize) -> bool {
+        if self.required == 0 {
+            return true;
+        }
+
+        while index < self.candidates.len() && self.occupied.contains(index) {
+            index += 1;
+        }
+
+        // Every cell before index is decided, so the occupied cells and the remaining shapes fully
+        // determine whether this state can be completed
+        let state = (self.occupied.clone(), self.remaining.clone());
+        if self.failed.contains(&state) {
+            return false;
+        }
+
+        if !self.can_cover_remaining(index) || !self.fill(index) {
+            self.failed.insert(state);
+            return false;
+        }
+
+        true
+    }
+
+    /// Checks if the cells that any remaining shape could still be placed on add up to at least the
+    /// area of the remaining shapes
+    fn can_cover_remaining(&self, index: usize) -> bool {
+        let mut coverable = Cells::new(self.candidates.len());
+
+        for candidate in self.candidates[index..].iter().flatten() {
+            if self.remaining[candidate.shape] > 0 && !candidate.cells.intersects(&self.occupied) {
+                coverable.union(&candidate.cells);
+            }
+        }
+
+        coverable.len() >= self.required
+    }
+
+    /// Tries every way to fill the first undecided cell
+    fn fill(&mut self, index: usize) -> bool {
+        for i in 0..self.candidates[index].len() {
+            let candidate = &self.candidates[index][i];
+            let shape = candidate.shape;
+
+            if self.remaining[shape] == 0 || candidate.cells.intersects(&self.occupied) {
+                continue;
+            }
+
+            self.occupied.toggle(&candidate.cells);
+            self.remaining[shape] -= 1;
+            self.required -= self.areas[shape];
+
+            if self.search(index + 1) {
+                let cells = self.candidates[index][i].points.clone();
+                self.placements.push(Placement { shape, cells });
+                return true;
+            }
+
+            let candidate = &self.candidates[index][i];
+            self.occupied.toggle(&candidate.cells);
+            self.remaining[shape] += 1;
+            self.required += self.areas[shape];
+        }
+
+        // Leave the cell empty, running out of space is caught by can_cover_remaining
+        self.occupied.insert(index);
+        if self.search(index + 1) {
+            return true;
+        }
+        self.occupied.remove(index);
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn parses_and_normalizes() {
+        let shape = Shape::parse(
+            "
+...
+.##
+.#.
+",
+        )
+        .unwrap();
+
+        assert_eq!(
+            shape.cells(),
+            &[Point::new(0, 0), Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!((shape.width(), shape.height()), (2, 2));
+        assert_eq!(shape.to_string(), "##\n#.");
+    }
+
+    #[rstest]
+    #[case("...\n...", "a shape needs at least one cell")]
+    #[case("#.\n.x", "unexpected character 'x' at 1,1")]
+    #[case("#.\n.", "all lines must be of equal width")]
+    fn parse_should_fail(#[case] input: &str, #[case] error: &str) {
+        assert_eq!(Shape::parse(input), Err(error.to_string()));
+    }
+
+    #[rstest]
+    #[case("##\n##", 1)]
+    #[case("####", 2)]
+    #[case("###\n.#.", 4)]
+    #[case(".##\n##.", 4)]
+    #[case("###\n#..", 8)]
+    #[case(".#.\n###\n.#.", 1)]
+    fn counts_unique_orientations(#[case] input: &str, #[case] expected: usize) {
+        let shape = Shape::parse(input).unwrap();
+
+        let orientations = shape.orientations();
+
+        assert_eq!(orientations.len(), expected);
+        assert_eq!(orientations[0], shape);
+        assert!(orientations.iter().all(|o| o.area() == shape.area()));
+    }
+
+    #[rstest]
+    fn packs_without_overlap() {
+        let shapes = [
+            Shape::parse("###\n#..").unwrap(),
+            Shape::parse("##\n##").unwrap(),
+        ];
+
+        let placements = pack(&shapes, &[2, 1], 4, 3).unwrap();
+
+        assert_eq!(placements.len(), 3);
+        assert_eq!(placements.iter().filter(|p| p.shape == 0).count(), 2);
+
+        let covered = placements.iter().flat_map(|p| &p.cells).collect_vec();
+        assert_eq!(covered.len(), covered.iter().collect::<HashSet<_>>().len());
+        assert!(covered.iter().all(|p| p.x < 4 && p.y < 3));
+    }
+
+    #[rstest]
+    fn rejects_when_shapes_do_not_fit() {
+        let line = Shape::parse("####").unwrap();
+        let square = Shape::parse("##\n##").unwrap();
+
+        // Enough area, but a line cannot fit next to the square in a 3x3 region
+        assert!(pack(&[line.clone(), square.clone()], &[1, 1], 3, 3).is_none());
+        assert!(pack(std::slice::from_ref(&line), &[5], 4, 4).is_none());
+        assert!(pack(&[line, square], &[2, 2], 4, 4).is_some());
+    }
+
+    #[rstest]
+    fn packs_nothing() {
+        let square = Shape::parse("##\n##").unwrap();
+
+        assert_eq!(pack(&[square], &[0], 1, 1), Some(vec![]));
+    }
+}
@@ -0,0 +1,322 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Disjoint-set over the dense indices 0..n, using path compression and union by size
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::union_find::UnionFind;
+///
+/// let mut sets = UnionFind::new(5);
+/// sets.union(0, 1);
+/// sets.union(3, 4);
+/// sets.union(1, 4);
+///
+/// assert!(sets.connected(0, 3));
+/// assert_eq!(sets.size(4), 4);
+/// assert_eq!(sets.count(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    /// Creates n disjoint sets, each holding a single index
+    pub fn new(n: usize) -> UnionFind {
+        UnionFind {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            count: n,
+        }
+    }
+
+    /// Adds a new set holding a single index, returning that index
+    pub fn add(&mut self) -> usize {
+        let index = self.parent.len();
+
+        self.parent.push(index);
+        self.size.push(1);
+        self.count += 1;
+
+        index
+    }
+
+    /// Returns the representative of the set containing x
+    /// Panics if x is out of range
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut current = x;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Merges the sets containing a and b. Returns false if they were already in the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (large, small) = if self.size[a] >= self.size[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+
+        self.parent[small] = large;
+        self.size[large] += self.size[small];
+        self.count -= 1;
+
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the size of the set containing x
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Number of disjoint sets
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Number of indices over all sets
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Returns the sizes of all sets, largest first
+    pub fn sizes(&self) -> Vec<usize> {
+        let mut sizes = (0..self.parent.len())
+            .filter(|i| self.parent[*i] == *i)
+            .map(|i| self.size[i])
+            .collect::<Vec<_>>();
+
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes
+    }
+
+    /// Returns the members of every set, ordered by their lowest index
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut groups: Vec<Vec<usize>> = vec![];
+        let mut group_of_root = HashMap::new();
+
+        for i in 0..self.parent.len() {
+            let root = self.find(i);
+            let group = *group_of_root.entry(root).or_insert_with(|| {
+                groups.push(vec![]);
+                groups.len() - 1
+            });
+
+            groups[group].push(i);
+        }
+
+        groups
+    }
+}
+
+/// Disjoint-set over arbitrary hashable keys, backed by a UnionFind. Keys are added on first use
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::union_find::KeyedUnionFind;
+///
+/// let mut sets = KeyedUnionFind::new();
+/// sets.union("a", "b");
+/// sets.union("c", "d");
+/// sets.insert("e");
+///
+/// assert!(sets.connected(&"a", &"b"));
+/// assert!(!sets.connected(&"a", &"c"));
+/// assert_eq!(sets.count(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyedUnionFind<K> {
+    indices: HashMap<K, usize>,
+    keys: Vec<K>,
+    sets: UnionFind,
+}
+
+impl<K: Hash + Eq + Clone> KeyedUnionFind<K> {
+    pub fn new() -> KeyedUnionFind<K> {
+        KeyedUnionFind {
+            indices: HashMap::new(),
+            keys: vec![],
+            sets: UnionFind::new(0),
+        }
+    }
+
+    /// Adds a key as its own set if not yet present, returning its dense index
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(index) = self.indices.get(&key) {
+            return *index;
+        }
+
+        let index = self.sets.add();
+        self.indices.insert(key.clone(), index);
+        self.keys.push(key);
+
+        index
+    }
+
+    /// Returns the representative key of the set containing key, None if the key is unknown
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let index = *self.indices.get(key)?;
+        let root = self.sets.find(index);
+
+        Some(&self.keys[root])
+    }
+
+    /// Merges the sets containing a and b, adding either key when not yet present. Returns false
+    /// if they were already in the same set
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let a = self.insert(a);
+        let b = self.insert(b);
+
+        self.sets.union(a, b)
+    }
+
+    /// Checks if both keys are in the same set, unknown keys are never connected
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.indices.get(a), self.indices.get(b)) {
+            (Some(a), Some(b)) => self.sets.connected(*a, *b),
+            _ => false,
+        }
+    }
+
+    /// Returns the size of the set containing key, None if the key is unknown
+    pub fn size(&mut self, key: &K) -> Option<usize> {
+        let index = *self.indices.get(key)?;
+
+        Some(self.sets.size(index))
+    }
+
+    /// Number of disjoint sets
+    pub fn count(&self) -> usize {
+        self.sets.count()
+    }
+
+    /// Number of keys over all sets
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the sizes of all sets, largest first
+    pub fn sizes(&self) -> Vec<usize> {
+        self.sets.sizes()
+    }
+
+    /// Returns the keys of every set, ordered by insertion
+    pub fn groups(&mut self) -> Vec<Vec<&K>> {
+        self.sets
+            .groups()
+            .into_iter()
+            .map(|group| group.into_iter().map(|i| &self.keys[i]).collect())
+            .collect()
+    }
+}
+
+impl<K: Hash + Eq + Clone> Default for KeyedUnionFind<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn it_tracks_sizes_and_count() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.count(), 6);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 1));
+        assert!(!sets.union(0, 2));
+        assert!(sets.union(4, 5));
+
+        assert_eq!(sets.count(), 3);
+        assert_eq!(sets.size(0), 3);
+        assert_eq!(sets.size(5), 2);
+        assert_eq!(sets.size(3), 1);
+        assert_eq!(sets.sizes(), vec![3, 2, 1]);
+        assert_eq!(sets.groups(), vec![vec![0, 1, 2], vec![3], vec![4, 5]]);
+    }
+
+    #[rstest]
+    fn it_can_grow() {
+        let mut sets = UnionFind::new(0);
+        assert!(sets.is_empty());
+
+        let a = sets.add();
+        let b = sets.add();
+        sets.union(a, b);
+
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets.count(), 1);
+        assert!(sets.connected(a, b));
+    }
+
+    #[rstest]
+    fn it_handles_long_chains() {
+        let n = 10_000;
+        let mut sets = UnionFind::new(n);
+
+        for i in 1..n {
+            sets.union(i - 1, i);
+        }
+
+        assert_eq!(sets.count(), 1);
+        assert_eq!(sets.size(0), n);
+        assert!(sets.connected(0, n - 1));
+    }
+
+    #[rstest]
+    fn keyed_sets_work_with_any_key() {
+        let mut sets = KeyedUnionFind::new();
+
+        sets.union((0, 0), (0, 1));
+        sets.union((0, 1), (1, 1));
+        sets.insert((5, 5));
+        sets.insert((0, 0));
+
+        assert_eq!(sets.len(), 4);
+        assert_eq!(sets.count(), 2);
+        assert_eq!(sets.size(&(1, 1)), Some(3));
+        assert_eq!(sets.size(&(9, 9)), None);
+        let root = sets.find(&(0, 0)).cloned();
+        assert_eq!(sets.find(&(1, 1)).cloned(), root);
+        assert!(!sets.connected(&(0, 0), &(9, 9)));
+        assert_eq!(
+            sets.groups(),
+            vec![vec![&(0, 0), &(0, 1), &(1, 1)], vec![&(5, 5)]]
+        );
+    }
+}
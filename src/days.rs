@@ -0,0 +1,16 @@
+//! Every solution, compiled into the runner with the `registry` feature so `cargo all` and
+//! `cargo time` can run them in-process. `cargo scaffold` adds new days here.
+advent_of_code::register_days! {
+    day01 => "bin/01.rs",
+    day02 => "bin/02.rs",
+    day03 => "bin/03.rs",
+    day04 => "bin/04.rs",
+    day05 => "bin/05.rs",
+    day06 => "bin/06.rs",
+    day07 => "bin/07.rs",
+    day08 => "bin/08.rs",
+    day09 => "bin/09.rs",
+    day10 => "bin/10.rs",
+    day11 => "bin/11.rs",
+    day12 => "bin/12.rs",
+}
@@ -4,37 +4,88 @@ use itertools::Itertools;
 
 use crate::components::Point;
 
-use super::Grid;
+use super::{Grid, transform::Transform};
 
-#[derive(PartialEq, Eq)]
+/// A grid of ASCII characters, stored as a flat row-major byte buffer so get and set are constant
+/// time. Only ASCII input is supported, as every char needs to map to exactly one byte.
+#[derive(PartialEq, Eq, Clone)]
 pub struct CharGrid {
-    lines: Vec<String>,
+    data: Vec<u8>,
+    width: usize,
+    height: usize,
 }
 
 impl CharGrid {
     /// Creates a new CharGrid from a string, splitting and trimming the lines
     /// Panics if the input is empty
     /// Panics if any of the lines differs in length
+    /// Panics if the input contains non-ASCII characters
     pub fn new(input: &str) -> CharGrid {
-        let lines: Vec<String> = input
-            .trim()
-            .lines()
-            .map(|line| line.trim().to_string())
-            .collect();
+        CharGrid::try_new(input).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Same as CharGrid::new, but returns an error instead of panicking on invalid input
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::grid::char_grid::CharGrid;
+    /// assert!(CharGrid::try_new("#.\n.#").is_ok());
+    /// assert_eq!(
+    ///     CharGrid::try_new("#.\n.é").unwrap_err(),
+    ///     "non-ASCII character 'é' at 1,1"
+    /// );
+    /// ```
+    pub fn try_new(input: &str) -> Result<CharGrid, String> {
+        let lines = input.trim().lines().map(|line| line.trim()).collect_vec();
+
+        let width = lines.first().map(|f| f.len()).unwrap_or(0);
+
+        if width == 0 {
+            return Err("line length cannot be empty".to_string());
+        }
+
+        let mut data = Vec::with_capacity(width * lines.len());
+
+        for (y, line) in lines.iter().enumerate() {
+            if let Some((x, c)) = line.chars().enumerate().find(|(_, c)| !c.is_ascii()) {
+                return Err(format!("non-ASCII character '{c}' at {x},{y}"));
+            }
+
+            if line.len() != width {
+                return Err("all lines must be of equal width".to_string());
+            }
+
+            data.extend_from_slice(line.as_bytes());
+        }
+
+        Ok(CharGrid {
+            data,
+            width,
+            height: lines.len(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
 
-        let line_len = lines
-            .first()
-            .map(|f| f.len())
-            .expect("failed to get a valid line length");
+    fn index(&self, point: &Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok()?;
+        let y = usize::try_from(point.y).ok()?;
 
-        assert!(line_len != 0, "line length cannot be empty");
+        if x >= self.width || y >= self.height {
+            return None;
+        }
 
-        assert!(
-            lines.iter().all(|f| f.len() == line_len),
-            "all lines must be of equal width"
-        );
+        Some(y * self.width + x)
+    }
 
-        CharGrid { lines }
+    fn point(&self, index: usize) -> Point {
+        Point::new((index % self.width) as isize, (index / self.width) as isize)
     }
 }
 
@@ -43,60 +94,74 @@ impl<'a> Grid<'a> for CharGrid {
     type SetItem = char;
 
     fn bounds(&self) -> (Point, Point) {
-        let line_len = self.lines.first().map(|f| f.len()).unwrap() - 1;
-        let height = self.lines.len() - 1;
-
         (
             Point::new(0, 0),
-            Point::new(line_len as isize, height as isize),
+            Point::new(self.width as isize - 1, self.height as isize - 1),
         )
     }
 
     fn get(&'a self, point: &Point) -> Option<Self::ReturnItem> {
-        if !self.in_bounds(point) {
-            return None;
-        }
-
-        self.lines
-            .get(usize::try_from(point.y).unwrap())
-            .and_then(|line| line.chars().nth(usize::try_from(point.x).unwrap()))
+        self.index(point).map(|i| self.data[i] as char)
     }
 
+    /// Panics if the point falls outside of the grid, or if the value is not an ASCII character
     fn set(&mut self, point: &Point, value: Self::SetItem) {
-        let line = self.lines.get(usize::try_from(point.y).unwrap()).unwrap();
-        let line = line
-            .chars()
-            .enumerate()
-            .map(|(x, c)| {
-                if x as isize == point.x {
-                    return value;
-                }
+        assert!(value.is_ascii(), "non-ASCII character '{value}'");
 
-                c
-            })
-            .collect::<String>();
+        let index = self
+            .index(point)
+            .unwrap_or_else(|| panic!("point {point} out of bounds"));
 
-        self.lines[usize::try_from(point.y).unwrap()] = line;
+        self.data[index] = value as u8;
     }
 
     fn keys(&self) -> impl Iterator<Item = Point> {
-        self.lines.iter().enumerate().flat_map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(move |(x, _c)| Point::new(x as isize, y as isize))
-        })
+        (0..self.data.len()).map(|i| self.point(i))
     }
 
     fn values(&'a self) -> impl Iterator<Item = Self::ReturnItem> {
-        self.lines.iter().flat_map(|line| line.chars())
+        self.data.iter().map(|b| *b as char)
     }
 
     fn entries(&'a self) -> impl Iterator<Item = (Point, Self::ReturnItem)> {
-        self.lines.iter().enumerate().flat_map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .map(move |(x, c)| (Point::new(x as isize, y as isize), c))
-        })
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, b)| (self.point(i), *b as char))
+    }
+}
+
+impl Transform for CharGrid {
+    fn dimensions(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    fn remap<F: Fn(Point) -> Point>(&self, width: usize, height: usize, map: F) -> Self {
+        let mut data = vec![0; self.data.len()];
+
+        for (i, value) in self.data.iter().enumerate() {
+            let target = map(self.point(i));
+            data[target.y as usize * width + target.x as usize] = *value;
+        }
+
+        CharGrid {
+            data,
+            width,
+            height,
+        }
+    }
+}
+
+impl Debug for CharGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.data
+                .chunks(self.width)
+                .map(|line| line.iter().map(|b| *b as char).collect::<String>())
+                .join("\n")
+        )
     }
 }
 
@@ -203,10 +268,30 @@ mod test {
 
         assert_eq!(result, input)
     }
-}
 
-impl Debug for CharGrid {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.lines.iter().join("\n"))
+    #[rstest]
+    #[should_panic(expected = "non-ASCII character")]
+    fn new_should_fail_on_non_ascii() {
+        CharGrid::new("|..|\n|.é|");
+    }
+
+    #[rstest]
+    fn set_should_not_touch_neighbours() {
+        let mut grid = CharGrid::new("...\n...\n...");
+
+        grid.set(&Point::new(2, 0), '#');
+        grid.set(&Point::new(0, 2), '#');
+
+        assert_eq!(format!("{:?}", grid), "..#\n...\n#..");
+        assert_eq!(grid.get(&Point::new(-1, 0)), None);
+        assert_eq!(grid.get(&Point::new(3, 0)), None);
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn set_should_panic_out_of_bounds() {
+        let mut grid = CharGrid::new("...\n...");
+
+        grid.set(&Point::new(0, 2), '#');
     }
 }
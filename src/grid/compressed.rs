@@ -0,0 +1,278 @@
+use crate::components::Point;
+
+use super::{Grid, hash_grid::HashGrid};
+
+/// One axis of a compression. Every seen value gets its own cell, and the values between two
+/// seen values that are not adjacent share a single gap cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Axis {
+    /// Real value at which every cell starts, a cell ends right before the next one starts
+    starts: Vec<isize>,
+    /// Last real value of the final cell
+    end: isize,
+}
+
+impl Axis {
+    fn new(mut values: Vec<isize>) -> Axis {
+        values.sort_unstable();
+        values.dedup();
+
+        let mut starts = vec![];
+        for (i, value) in values.iter().enumerate() {
+            starts.push(*value);
+
+            if values.get(i + 1).is_some_and(|next| *next > value + 1) {
+                starts.push(value + 1);
+            }
+        }
+
+        Axis {
+            starts,
+            end: *values.last().unwrap(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.starts.len()
+    }
+
+    fn compress(&self, value: isize) -> Option<isize> {
+        if value < self.starts[0] || value > self.end {
+            return None;
+        }
+
+        Some(self.starts.partition_point(|start| *start <= value) as isize - 1)
+    }
+
+    /// Real first and last value of a cell
+    fn span(&self, index: isize) -> Option<(isize, isize)> {
+        let index = usize::try_from(index).ok()?;
+        let start = *self.starts.get(index)?;
+        let end = self.starts.get(index + 1).map_or(self.end, |next| next - 1);
+
+        Some((start, end))
+    }
+}
+
+/// Maps huge sparse coordinates onto a compact grid. Sorted unique x and y values each get their
+/// own row or column, with a single row or column standing in for every gap between them. Every
+/// compressed cell covers a real rectangle, so areas can be mapped back exactly
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::Point;
+/// use advent_of_code::grid::compressed::Compression;
+///
+/// let points = [Point::new(10, 0), Point::new(1_000_000, 500)];
+/// let compression = Compression::new(&points);
+///
+/// assert_eq!((compression.width(), compression.height()), (3, 3));
+/// assert_eq!(compression.compress(&Point::new(1_000_000, 500)), Some(Point::new(2, 2)));
+/// assert_eq!(compression.compress(&Point::new(123, 45)), Some(Point::new(1, 1)));
+/// assert_eq!(compression.size(&Point::new(1, 1)), Some(Point::new(999_989, 499)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Compression {
+    xs: Axis,
+    ys: Axis,
+}
+
+impl Compression {
+    /// Compresses the coordinates of all given points
+    /// Panics if there are no points
+    pub fn new<'p, I>(points: I) -> Compression
+    where
+        I: IntoIterator<Item = &'p Point>,
+    {
+        let (xs, ys): (Vec<_>, Vec<_>) = points.into_iter().map(|p| (p.x, p.y)).unzip();
+        assert!(!xs.is_empty(), "cannot compress without points");
+
+        Compression {
+            xs: Axis::new(xs),
+            ys: Axis::new(ys),
+        }
+    }
+
+    /// Like `new`, but with an extra row and column on every side. Useful to flood fill the
+    /// outside of a shape
+    pub fn bordered<'p, I>(points: I) -> Compression
+    where
+        I: IntoIterator<Item = &'p Point>,
+    {
+        let points = points.into_iter().collect::<Vec<_>>();
+        let compression = Compression::new(points.iter().copied());
+        let lower = Point::new(compression.xs.starts[0], compression.ys.starts[0]);
+        let upper = Point::new(compression.xs.end, compression.ys.end);
+
+        Compression::new(
+            points
+                .into_iter()
+                .chain([&(lower - Point::new(1, 1)), &(upper + Point::new(1, 1))]),
+        )
+    }
+
+    /// Number of compressed columns
+    pub fn width(&self) -> usize {
+        self.xs.len()
+    }
+
+    /// Number of compressed rows
+    pub fn height(&self) -> usize {
+        self.ys.len()
+    }
+
+    /// The compressed cell holding a real point, None outside of the compressed range
+    pub fn compress(&self, point: &Point) -> Option<Point> {
+        Some(Point::new(
+            self.xs.compress(point.x)?,
+            self.ys.compress(point.y)?,
+        ))
+    }
+
+    /// The real rectangle covered by a compressed cell, as its lower and upper corner
+    pub fn decompress(&self, cell: &Point) -> Option<(Point, Point)> {
+        let (x1, x2) = self.xs.span(cell.x)?;
+        let (y1, y2) = self.ys.span(cell.y)?;
+
+        Some((Point::new(x1, y1), Point::new(x2, y2)))
+    }
+
+    /// Real width and height of a compressed cell
+    pub fn size(&self, cell: &Point) -> Option<Point> {
+        let (lower, upper) = self.decompress(cell)?;
+
+        Some(upper - lower + Point::new(1, 1))
+    }
+
+    /// A grid holding every compressed cell, with its real width and height as value
+    pub fn cells<'a>(&self) -> HashGrid<'a, Point> {
+        let mut grid = HashGrid::new();
+
+        for y in 0..self.height() as isize {
+            for x in 0..self.width() as isize {
+                let cell = Point::new(x, y);
+                grid.set(&cell, self.size(&cell).unwrap());
+            }
+        }
+
+        grid
+    }
+
+    /// Total real area covered by the given compressed cells
+    pub fn area<I>(&self, cells: I) -> u64
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        cells
+            .into_iter()
+            .filter_map(|cell| self.size(&cell))
+            .map(|size| (size.x * size.y) as u64)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use itertools::Itertools;
+    use rstest::rstest;
+
+    use crate::{components::polygon::Polygon, grid::regions::flood_fill_by};
+
+    use super::*;
+
+    fn points(points: &[(isize, isize)]) -> Vec<Point> {
+        points.iter().map(|(x, y)| Point::new(*x, *y)).collect()
+    }
+
+    #[rstest]
+    fn it_adds_gap_cells() {
+        let compression = Compression::new(&points(&[(1, 7), (2, 3), (9, 3), (5, 100)]));
+
+        assert_eq!((compression.width(), compression.height()), (6, 5));
+        assert_eq!(
+            compression.compress(&Point::new(3, 50)),
+            Some(Point::new(2, 3))
+        );
+        assert_eq!(
+            compression.compress(&Point::new(9, 3)),
+            Some(Point::new(5, 0))
+        );
+        assert_eq!(compression.compress(&Point::new(0, 3)), None);
+        assert_eq!(compression.compress(&Point::new(10, 3)), None);
+
+        assert_eq!(
+            compression.decompress(&Point::new(4, 4)),
+            Some((Point::new(6, 100), Point::new(8, 100)))
+        );
+        assert_eq!(compression.decompress(&Point::new(6, 0)), None);
+    }
+
+    #[rstest]
+    fn cells_cover_the_full_range() {
+        let compression = Compression::new(&points(&[(-5, 3), (20, 8), (3, 30)]));
+
+        let cells = compression.cells();
+
+        assert_eq!(
+            cells.keys().count(),
+            compression.width() * compression.height()
+        );
+        assert_eq!(compression.area(cells.keys()), 26 * 28);
+        assert_eq!(cells.get(&Point::new(1, 1)), Some(&Point::new(7, 4)));
+    }
+
+    #[rstest]
+    fn bordered_adds_a_ring_of_cells() {
+        let compression = Compression::bordered(&points(&[(0, 0), (10, 10)]));
+
+        assert_eq!((compression.width(), compression.height()), (5, 5));
+        assert_eq!(
+            compression.compress(&Point::new(-1, 11)),
+            Some(Point::new(0, 4))
+        );
+    }
+
+    #[rstest]
+    fn it_measures_huge_polygons() {
+        let vertices = points(&[
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ])
+        .into_iter()
+        .map(|p| p * 100_000)
+        .collect_vec();
+        let compression = Compression::bordered(&vertices);
+
+        let mut walls = HashSet::new();
+        for (a, b) in vertices.iter().circular_tuple_windows() {
+            let (a, b) = (
+                compression.compress(a).unwrap(),
+                compression.compress(b).unwrap(),
+            );
+            for x in a.x.min(b.x)..=a.x.max(b.x) {
+                for y in a.y.min(b.y)..=a.y.max(b.y) {
+                    walls.insert(Point::new(x, y));
+                }
+            }
+        }
+
+        let cells = compression.cells();
+        let outside = flood_fill_by(&cells, Point::new(0, 0), &Point::DIRECTIONS_4, |p, _| {
+            !walls.contains(p)
+        });
+        let inside = cells.keys().filter(|cell| !outside.contains(cell));
+
+        assert_eq!(
+            compression.area(inside) as usize,
+            Polygon::new(vertices).lattice_points()
+        );
+    }
+}
@@ -2,7 +2,7 @@ use std::{collections::HashMap, marker::PhantomData};
 
 use crate::components::Point;
 
-use super::{char_grid::CharGrid, Grid};
+use super::{Grid, char_grid::CharGrid, transform::Transform};
 
 #[derive(PartialEq, Eq, Clone)]
 pub struct HashGrid<'a, T> {
@@ -117,6 +117,25 @@ impl<'a, T> Grid<'a> for HashGrid<'a, T> {
     }
 }
 
+impl<'a, T: Clone> Transform for HashGrid<'a, T> {
+    fn dimensions(&self) -> (usize, usize) {
+        let size = self.upper - self.lower;
+
+        (size.x as usize + 1, size.y as usize + 1)
+    }
+
+    fn remap<F: Fn(Point) -> Point>(&self, width: usize, height: usize, map: F) -> Self {
+        let upper = self.lower + Point::new(width as isize - 1, height as isize - 1);
+        let mut grid = HashGrid::with_bounds(self.lower, upper);
+
+        for (point, value) in &self.grid {
+            grid.set(&(map(*point - self.lower) + self.lower), value.clone());
+        }
+
+        grid
+    }
+}
+
 #[cfg(test)]
 mod test {
     use rstest::rstest;
@@ -1,5 +1,11 @@
 pub mod char_grid;
+pub mod compressed;
 pub mod hash_grid;
+pub mod prefix_sum;
+pub mod regions;
+pub mod search;
+pub mod transform;
+pub mod vec_grid;
 
 use crate::components::Point;
 
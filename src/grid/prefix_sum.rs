@@ -0,0 +1,281 @@
+use crate::components::Point;
+
+use super::{Grid, vec_grid::VecGrid};
+
+/// Clamps the rectangle with corners a and b to a grid of width by height at `lower`, returning
+/// the corners relative to `lower`. None if they do not overlap
+fn clamp(
+    lower: Point,
+    width: usize,
+    height: usize,
+    a: &Point,
+    b: &Point,
+) -> Option<(Point, Point)> {
+    let (a, b) = (*a - lower, *b - lower);
+
+    let from = Point::new(a.x.min(b.x).max(0), a.y.min(b.y).max(0));
+    let to = Point::new(
+        a.x.max(b.x).min(width as isize - 1),
+        a.y.max(b.y).min(height as isize - 1),
+    );
+
+    (from.x <= to.x && from.y <= to.y).then_some((from, to))
+}
+
+/// A summed-area table over the bounds of a grid, answering the sum of any rectangle in constant
+/// time
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::Point;
+/// use advent_of_code::grid::{char_grid::CharGrid, prefix_sum::SummedArea};
+///
+/// let grid = CharGrid::new("@.@\n.@@\n@@.");
+/// let table = SummedArea::new(&grid, |c| (c == '@') as i64);
+///
+/// assert_eq!(table.total(), 6);
+/// assert_eq!(table.sum(&Point::new(1, 1), &Point::new(2, 2)), 3);
+/// assert_eq!(table.sum(&Point::new(2, 0), &Point::new(0, 0)), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummedArea {
+    lower: Point,
+    width: usize,
+    height: usize,
+    /// Sum of every rectangle from the lower corner up to, but excluding, x,y. Has an extra row
+    /// and column of zeroes so lookups never go out of bounds
+    sums: Vec<i64>,
+}
+
+impl SummedArea {
+    /// Builds the table over the bounds of `grid`, mapping every value to a number. Points
+    /// without a value count as zero
+    pub fn new<'a, G, F>(grid: &'a G, value: F) -> SummedArea
+    where
+        G: Grid<'a>,
+        F: Fn(G::ReturnItem) -> i64,
+    {
+        let (lower, upper) = grid.bounds();
+        let width = (upper.x - lower.x + 1) as usize;
+        let height = (upper.y - lower.y + 1) as usize;
+        let stride = width + 1;
+
+        let mut sums = vec![0; stride * (height + 1)];
+        for y in 0..height {
+            for x in 0..width {
+                let point = lower + Point::new(x as isize, y as isize);
+                let value = grid.get(&point).map(&value).unwrap_or(0);
+
+                sums[(y + 1) * stride + x + 1] =
+                    value + sums[y * stride + x + 1] + sums[(y + 1) * stride + x]
+                        - sums[y * stride + x];
+            }
+        }
+
+        SummedArea {
+            lower,
+            width,
+            height,
+            sums,
+        }
+    }
+
+    fn at(&self, x: isize, y: isize) -> i64 {
+        self.sums[y as usize * (self.width + 1) + x as usize]
+    }
+
+    /// Sum of the rectangle with opposite corners a and b, both inclusive. Parts of the
+    /// rectangle outside of the grid count as zero
+    pub fn sum(&self, a: &Point, b: &Point) -> i64 {
+        let Some((from, to)) = clamp(self.lower, self.width, self.height, a, b) else {
+            return 0;
+        };
+
+        self.at(to.x + 1, to.y + 1) - self.at(from.x, to.y + 1) - self.at(to.x + 1, from.y)
+            + self.at(from.x, from.y)
+    }
+
+    /// Sum of the whole grid
+    pub fn total(&self) -> i64 {
+        self.at(self.width as isize, self.height as isize)
+    }
+}
+
+/// A difference array for adding values to whole rectangles in constant time, resolved into a
+/// grid once all updates are done. Bounds always start at 0,0, like a VecGrid
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::Point;
+/// use advent_of_code::grid::{Grid, prefix_sum::DifferenceGrid};
+///
+/// let mut updates = DifferenceGrid::new(4, 4);
+/// updates.add(&Point::new(0, 0), &Point::new(2, 2), 1);
+/// updates.add(&Point::new(1, 1), &Point::new(3, 3), 2);
+///
+/// let grid = updates.resolve();
+/// assert_eq!(grid.get(&Point::new(0, 0)), Some(&1));
+/// assert_eq!(grid.get(&Point::new(2, 2)), Some(&3));
+/// assert_eq!(grid.get(&Point::new(3, 0)), Some(&0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DifferenceGrid {
+    width: usize,
+    height: usize,
+    /// Has an extra row and column so updates reaching the far edge need no bounds checks
+    diffs: Vec<i64>,
+}
+
+impl DifferenceGrid {
+    /// Creates a difference grid of width by height, all zeroes
+    /// Panics if the width or height is zero
+    pub fn new(width: usize, height: usize) -> DifferenceGrid {
+        assert!(width != 0 && height != 0, "grid dimensions cannot be empty");
+
+        DifferenceGrid {
+            width,
+            height,
+            diffs: vec![0; (width + 1) * (height + 1)],
+        }
+    }
+
+    fn update(&mut self, x: isize, y: isize, value: i64) {
+        self.diffs[y as usize * (self.width + 1) + x as usize] += value;
+    }
+
+    /// Adds value to every point of the rectangle with opposite corners a and b, both inclusive.
+    /// Parts of the rectangle outside of the grid are ignored
+    pub fn add(&mut self, a: &Point, b: &Point, value: i64) {
+        let Some((from, to)) = clamp(Point::new(0, 0), self.width, self.height, a, b) else {
+            return;
+        };
+
+        self.update(from.x, from.y, value);
+        self.update(to.x + 1, from.y, -value);
+        self.update(from.x, to.y + 1, -value);
+        self.update(to.x + 1, to.y + 1, value);
+    }
+
+    /// Applies all updates, returning the value of every point
+    pub fn resolve(&self) -> VecGrid<i64> {
+        let stride = self.width + 1;
+        let mut sums = self.diffs.clone();
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let mut sum = sums[y * stride + x];
+                if x > 0 {
+                    sum += sums[y * stride + x - 1];
+                }
+                if y > 0 {
+                    sum += sums[(y - 1) * stride + x];
+                }
+                if x > 0 && y > 0 {
+                    sum -= sums[(y - 1) * stride + x - 1];
+                }
+
+                sums[y * stride + x] = sum;
+            }
+        }
+
+        VecGrid::from_rows(
+            sums.chunks(stride)
+                .take(self.height)
+                .map(|row| row[..self.width].to_vec())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use rstest::rstest;
+
+    use crate::grid::{char_grid::CharGrid, hash_grid::HashGrid};
+
+    use super::*;
+
+    fn rolls() -> CharGrid {
+        CharGrid::new(
+            "
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+",
+        )
+    }
+
+    #[rstest]
+    fn it_matches_counting_windows() {
+        let grid = rolls();
+        let table = SummedArea::new(&grid, |c| (c == '@') as i64);
+
+        for (a, b) in grid
+            .keys()
+            .collect_vec()
+            .into_iter()
+            .tuple_combinations()
+            .step_by(7)
+        {
+            let expected = grid
+                .entries()
+                .filter(|(p, c)| {
+                    *c == '@'
+                        && (p.is_within(&a, &b)
+                            || p.is_within(&Point::new(a.x, b.y), &Point::new(b.x, a.y)))
+                })
+                .count() as i64;
+
+            assert_eq!(table.sum(&a, &b), expected, "{a} to {b}");
+        }
+    }
+
+    #[rstest]
+    fn it_clamps_rectangles_to_the_grid() {
+        let table = SummedArea::new(&rolls(), |c| (c == '@') as i64);
+
+        assert_eq!(
+            table.sum(&Point::new(-5, -5), &Point::new(20, 20)),
+            table.total()
+        );
+        assert_eq!(table.sum(&Point::new(-5, 0), &Point::new(0, 0)), 0);
+        assert_eq!(table.sum(&Point::new(10, 0), &Point::new(12, 9)), 0);
+        assert_eq!(table.total(), 71);
+    }
+
+    #[rstest]
+    fn it_uses_the_grid_bounds() {
+        let mut grid = HashGrid::with_bounds(Point::new(-3, -3), Point::new(-1, -1));
+        grid.set(&Point::new(-3, -3), 5);
+        grid.set(&Point::new(-1, -2), 7);
+
+        let table = SummedArea::new(&grid, |v| *v);
+
+        assert_eq!(table.total(), 12);
+        assert_eq!(table.sum(&Point::new(-2, -3), &Point::new(-1, -1)), 7);
+        assert_eq!(table.sum(&Point::new(0, 0), &Point::new(3, 3)), 0);
+    }
+
+    #[rstest]
+    fn it_resolves_rectangle_updates() {
+        let mut updates = DifferenceGrid::new(5, 3);
+        updates.add(&Point::new(0, 0), &Point::new(4, 2), 1);
+        updates.add(&Point::new(3, 2), &Point::new(1, 1), 10);
+        updates.add(&Point::new(4, -1), &Point::new(9, 0), -1);
+
+        let grid = updates.resolve();
+
+        assert_eq!(grid.row(0), &[1, 1, 1, 1, 0]);
+        assert_eq!(grid.row(1), &[1, 11, 11, 11, 1]);
+        assert_eq!(grid.row(2), &[1, 11, 11, 11, 1]);
+        assert_eq!(SummedArea::new(&grid, |v| *v).total(), 74);
+    }
+}
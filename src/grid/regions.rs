@@ -0,0 +1,251 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::components::Point;
+
+use super::{Grid, hash_grid::HashGrid, search::bfs};
+
+/// A connected group of points holding equal values
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub id: usize,
+    /// The first point of the region in reading order
+    pub start: Point,
+    pub area: usize,
+    /// Number of edges between a point of the region and a point outside of it. Always counted
+    /// over the 4 straight directions, independent of the connectivity used to build the region
+    pub perimeter: usize,
+    pub lower: Point,
+    pub upper: Point,
+}
+
+/// All connected components of a grid
+pub struct Components<'a> {
+    /// Region id for every labelled point of the grid
+    pub labels: HashGrid<'a, usize>,
+    /// Regions, indexed by their id
+    pub regions: Vec<Region>,
+}
+
+/// Returns all points reachable from `seed` through points holding the same value as the seed.
+/// Returns an empty set if the seed holds no value.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::Point;
+/// use advent_of_code::grid::{char_grid::CharGrid, regions::flood_fill};
+///
+/// let grid = CharGrid::new("AAB\nABB\nAAB");
+/// assert_eq!(flood_fill(&grid, Point::new(0, 0), &Point::DIRECTIONS_4).len(), 5);
+/// assert_eq!(flood_fill(&grid, Point::new(2, 0), &Point::DIRECTIONS_4).len(), 4);
+/// ```
+pub fn flood_fill<'a, G>(grid: &'a G, seed: Point, directions: &[Point]) -> HashSet<Point>
+where
+    G: Grid<'a>,
+    G::ReturnItem: PartialEq,
+{
+    let Some(value) = grid.get(&seed) else {
+        return HashSet::new();
+    };
+
+    flood_fill_by(grid, seed, directions, |_, v| v == value)
+}
+
+/// Returns all points reachable from `seed` through points for which `include` returns true, the
+/// seed itself is always included. Useful to find the reachable area of a grid.
+pub fn flood_fill_by<'a, G, F>(
+    grid: &'a G,
+    seed: Point,
+    directions: &[Point],
+    include: F,
+) -> HashSet<Point>
+where
+    G: Grid<'a>,
+    F: Fn(&Point, G::ReturnItem) -> bool,
+{
+    bfs(grid, seed, directions, |_, next, value| {
+        include(next, value)
+    })
+    .distances
+    .into_keys()
+    .collect()
+}
+
+/// Labels every point of the grid with the id of the region of equal values it belongs to.
+/// Regions are numbered in reading order of their first point. Points for which the grid returns
+/// None are not labelled.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::Point;
+/// use advent_of_code::grid::{Grid, char_grid::CharGrid, regions::connected_components};
+///
+/// let grid = CharGrid::new("AAB\nABB\nBAA");
+/// let components = connected_components(&grid, &Point::DIRECTIONS_4);
+///
+/// assert_eq!(components.regions.len(), 4);
+/// assert_eq!(components.labels.get(&Point::new(2, 1)), Some(&1));
+///
+/// let components = connected_components(&grid, &Point::DIRECTIONS_8);
+/// assert_eq!(components.regions.len(), 2);
+/// ```
+pub fn connected_components<'a, 'b, G>(grid: &'a G, directions: &[Point]) -> Components<'b>
+where
+    G: Grid<'a>,
+    G::ReturnItem: PartialEq,
+{
+    let (lower, upper) = grid.bounds();
+    let mut labels = HashGrid::with_bounds(lower, upper);
+    let mut regions = vec![];
+
+    let points = grid.keys().sorted_by_key(|p| (p.y, p.x)).collect_vec();
+
+    for start in points {
+        if labels.contains(&start) {
+            continue;
+        }
+
+        let id = regions.len();
+        let members = flood_fill(grid, start, directions);
+
+        let mut region = Region {
+            id,
+            start,
+            area: members.len(),
+            perimeter: 0,
+            lower: start,
+            upper: start,
+        };
+
+        for point in &members {
+            labels.set(point, id);
+
+            region.perimeter += Point::DIRECTIONS_4
+                .iter()
+                .filter(|d| !members.contains(&(*point + **d)))
+                .count();
+
+            region.lower.x = region.lower.x.min(point.x);
+            region.lower.y = region.lower.y.min(point.y);
+            region.upper.x = region.upper.x.max(point.x);
+            region.upper.y = region.upper.y.max(point.y);
+        }
+
+        regions.push(region);
+    }
+
+    Components { labels, regions }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use crate::grid::char_grid::CharGrid;
+
+    use super::*;
+
+    fn garden() -> CharGrid {
+        CharGrid::new(
+            "
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+",
+        )
+    }
+
+    #[rstest]
+    fn flood_fill_stays_within_equal_values() {
+        let grid = garden();
+
+        let filled = flood_fill(&grid, Point::new(0, 0), &Point::DIRECTIONS_4);
+        assert_eq!(filled.len(), 21);
+        assert!(!filled.contains(&Point::new(1, 1)));
+
+        let filled = flood_fill(&grid, Point::new(1, 1), &Point::DIRECTIONS_8);
+        assert_eq!(filled, HashSet::from([Point::new(1, 1)]));
+
+        assert!(flood_fill(&grid, Point::new(9, 9), &Point::DIRECTIONS_4).is_empty());
+    }
+
+    #[rstest]
+    fn flood_fill_by_finds_reachable_area() {
+        let grid = CharGrid::new("S.#.\n..#.\n###.");
+
+        let reachable = flood_fill_by(&grid, Point::new(0, 0), &Point::DIRECTIONS_4, |_, c| {
+            c != '#'
+        });
+
+        assert_eq!(reachable.len(), 4);
+    }
+
+    #[rstest]
+    fn components_calculate_area_and_perimeter() {
+        let grid = garden();
+
+        let components = connected_components(&grid, &Point::DIRECTIONS_4);
+
+        assert_eq!(components.regions.len(), 5);
+
+        let outer = &components.regions[0];
+        assert_eq!(outer.area, 21);
+        assert_eq!(outer.perimeter, 36);
+        assert_eq!(
+            (outer.lower, outer.upper),
+            (Point::new(0, 0), Point::new(4, 4))
+        );
+
+        for region in &components.regions[1..] {
+            assert_eq!(region.area, 1);
+            assert_eq!(region.perimeter, 4);
+            assert_eq!(region.lower, region.upper);
+        }
+
+        assert_eq!(components.labels.get(&Point::new(3, 3)), Some(&4));
+        assert_eq!(components.labels.bounds(), grid.bounds());
+    }
+
+    #[rstest]
+    fn components_with_diagonals() {
+        let grid = CharGrid::new(
+            "
+X..
+.X.
+..X
+",
+        );
+
+        let straight = connected_components(&grid, &Point::DIRECTIONS_4);
+        let diagonal = connected_components(&grid, &Point::DIRECTIONS_8);
+
+        assert_eq!(straight.regions.len(), 5);
+        assert_eq!(diagonal.regions.len(), 2);
+
+        let line = &diagonal.regions[0];
+        assert_eq!(line.area, 3);
+        assert_eq!(line.perimeter, 12);
+        assert_eq!(
+            (line.lower, line.upper),
+            (Point::new(0, 0), Point::new(2, 2))
+        );
+    }
+
+    #[rstest]
+    fn components_skip_unset_points() {
+        let mut grid = HashGrid::new();
+        grid.set(&Point::new(0, 0), 'a');
+        grid.set(&Point::new(1, 0), 'a');
+        grid.set(&Point::new(3, 0), 'a');
+
+        let components = connected_components(&grid, &Point::DIRECTIONS_4);
+
+        assert_eq!(components.regions.len(), 2);
+        assert_eq!(components.regions[0].area, 2);
+        assert_eq!(components.regions[0].perimeter, 6);
+        assert!(!components.labels.contains(&Point::new(2, 0)));
+    }
+}
@@ -0,0 +1,447 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+};
+
+use crate::components::Point;
+
+use super::Grid;
+
+/// The result of exploring a grid from a single start point
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult {
+    pub start: Point,
+    /// The cost of the cheapest route from the start to every reached point
+    pub distances: HashMap<Point, u64>,
+    /// The point each reached point was entered from on its cheapest route
+    pub predecessors: HashMap<Point, Point>,
+}
+
+impl SearchResult {
+    fn new(start: Point) -> SearchResult {
+        SearchResult {
+            start,
+            distances: HashMap::from([(start, 0)]),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    /// Returns the cost of reaching a point, None if the point was not reached
+    pub fn distance(&self, point: &Point) -> Option<u64> {
+        self.distances.get(point).copied()
+    }
+
+    /// Reconstructs the route from the start to `target`, including both ends. Returns None if the
+    /// target was not reached
+    pub fn path_to(&self, target: &Point) -> Option<Vec<Point>> {
+        if !self.distances.contains_key(target) {
+            return None;
+        }
+
+        let mut path = vec![*target];
+        let mut current = *target;
+
+        while let Some(previous) = self.predecessors.get(&current) {
+            path.push(*previous);
+            current = *previous;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Priority queue entry, ordered so the BinaryHeap pops the lowest cost first
+#[derive(PartialEq, Eq)]
+struct State {
+    cost: u64,
+    point: Point,
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Breadth first search from `start`, stepping in each of `directions` (usually
+/// `Point::DIRECTIONS_4` or `Point::DIRECTIONS_8`).
+///
+/// `passable` is called with the current point, the next point and the value of the next point
+/// and decides if the step can be made. Points for which the grid returns None are never entered.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::Point;
+/// use advent_of_code::grid::{char_grid::CharGrid, search::bfs};
+///
+/// let grid = CharGrid::new("..#\n.##\n...");
+/// let result = bfs(&grid, Point::new(0, 0), &Point::DIRECTIONS_4, |_, _, c| c != '#');
+///
+/// assert_eq!(result.distance(&Point::new(2, 2)), Some(4));
+/// assert_eq!(result.distance(&Point::new(2, 0)), None);
+/// ```
+pub fn bfs<'a, G, F>(grid: &'a G, start: Point, directions: &[Point], passable: F) -> SearchResult
+where
+    G: Grid<'a>,
+    F: Fn(&Point, &Point, G::ReturnItem) -> bool,
+{
+    let mut result = SearchResult::new(start);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(point) = queue.pop_front() {
+        let distance = result.distances[&point];
+
+        for direction in directions {
+            let next = point + *direction;
+
+            if result.distances.contains_key(&next) {
+                continue;
+            }
+
+            let Some(value) = grid.get(&next) else {
+                continue;
+            };
+
+            if !passable(&point, &next, value) {
+                continue;
+            }
+
+            result.distances.insert(next, distance + 1);
+            result.predecessors.insert(next, point);
+            queue.push_back(next);
+        }
+    }
+
+    result
+}
+
+/// Dijkstra's shortest path from `start` to every reachable point.
+///
+/// `cost` is called with the current point, the next point and the value of the next point and
+/// returns the cost of the step, or None if the step cannot be made.
+pub fn dijkstra<'a, G, F>(grid: &'a G, start: Point, directions: &[Point], cost: F) -> SearchResult
+where
+    G: Grid<'a>,
+    F: Fn(&Point, &Point, G::ReturnItem) -> Option<u64>,
+{
+    let mut result = SearchResult::new(start);
+    let mut heap = BinaryHeap::from([State {
+        cost: 0,
+        point: start,
+    }]);
+
+    while let Some(State {
+        cost: current,
+        point,
+    }) = heap.pop()
+    {
+        if result.distances.get(&point).is_some_and(|d| *d < current) {
+            continue;
+        }
+
+        for direction in directions {
+            let next = point + *direction;
+
+            let Some(value) = grid.get(&next) else {
+                continue;
+            };
+
+            let Some(step) = cost(&point, &next, value) else {
+                continue;
+            };
+
+            let total = current + step;
+            if result.distances.get(&next).is_some_and(|d| *d <= total) {
+                continue;
+            }
+
+            result.distances.insert(next, total);
+            result.predecessors.insert(next, point);
+            heap.push(State {
+                cost: total,
+                point: next,
+            });
+        }
+    }
+
+    result
+}
+
+/// A* search from `start` to `goal`, returning the total cost and the route including both ends.
+///
+/// `cost` behaves the same as in `dijkstra`. `heuristic` estimates the remaining cost from a
+/// point to the goal and must never overestimate it for the result to be the cheapest route.
+/// When every step costs at least 1, `Point::distance` to the goal is a good fit for
+/// `DIRECTIONS_4`. It overestimates with `DIRECTIONS_8`, use `Point::chebyshev_distance` there.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::Point;
+/// use advent_of_code::grid::{char_grid::CharGrid, search::astar};
+///
+/// let grid = CharGrid::new("..#\n.##\n...");
+/// let goal = Point::new(2, 2);
+/// let (cost, path) = astar(
+///     &grid,
+///     Point::new(0, 0),
+///     goal,
+///     &Point::DIRECTIONS_4,
+///     |_, _, c| (c != '#').then_some(1),
+///     |p| p.distance(&goal) as u64,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(cost, 4);
+/// assert_eq!(path.len(), 5);
+/// ```
+pub fn astar<'a, G, F, H>(
+    grid: &'a G,
+    start: Point,
+    goal: Point,
+    directions: &[Point],
+    cost: F,
+    heuristic: H,
+) -> Option<(u64, Vec<Point>)>
+where
+    G: Grid<'a>,
+    F: Fn(&Point, &Point, G::ReturnItem) -> Option<u64>,
+    H: Fn(&Point) -> u64,
+{
+    let mut result = SearchResult::new(start);
+    let mut heap = BinaryHeap::from([State {
+        cost: heuristic(&start),
+        point: start,
+    }]);
+
+    while let Some(State {
+        cost: estimate,
+        point,
+    }) = heap.pop()
+    {
+        let current = result.distances[&point];
+
+        // a cheaper route to this point was found after this entry was pushed
+        if estimate > current + heuristic(&point) {
+            continue;
+        }
+
+        if point == goal {
+            return Some((current, result.path_to(&goal).unwrap()));
+        }
+
+        for direction in directions {
+            let next = point + *direction;
+
+            let Some(value) = grid.get(&next) else {
+                continue;
+            };
+
+            let Some(step) = cost(&point, &next, value) else {
+                continue;
+            };
+
+            let total = current + step;
+            if result.distances.get(&next).is_some_and(|d| *d <= total) {
+                continue;
+            }
+
+            result.distances.insert(next, total);
+            result.predecessors.insert(next, point);
+            heap.push(State {
+                cost: total + heuristic(&next),
+                point: next,
+            });
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use crate::grid::{char_grid::CharGrid, hash_grid::HashGrid, vec_grid::VecGrid};
+
+    use super::*;
+
+    fn maze() -> CharGrid {
+        CharGrid::new(
+            "
+S.#.....
+.##.###.
+....#...
+.####.#.
+......#E
+",
+        )
+    }
+
+    #[rstest]
+    fn bfs_finds_shortest_path() {
+        let grid = maze();
+        let start = Point::new(0, 0);
+        let end = Point::new(7, 4);
+
+        let result = bfs(&grid, start, &Point::DIRECTIONS_4, |_, _, c| c != '#');
+
+        assert_eq!(result.distance(&start), Some(0));
+        assert_eq!(result.distance(&end), Some(15));
+
+        let path = result.path_to(&end).unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&end));
+        assert!(path.iter().all(|p| grid.get(p) != Some('#')));
+        assert!(path.windows(2).all(|w| w[0].distance(&w[1]) == 1));
+    }
+
+    #[rstest]
+    fn bfs_respects_directions() {
+        let grid = CharGrid::new(".#\n#.");
+
+        let straight = bfs(&grid, Point::new(0, 0), &Point::DIRECTIONS_4, |_, _, c| {
+            c != '#'
+        });
+        let diagonal = bfs(&grid, Point::new(0, 0), &Point::DIRECTIONS_8, |_, _, c| {
+            c != '#'
+        });
+
+        assert_eq!(straight.distance(&Point::new(1, 1)), None);
+        assert_eq!(straight.path_to(&Point::new(1, 1)), None);
+        assert_eq!(diagonal.distance(&Point::new(1, 1)), Some(1));
+    }
+
+    #[rstest]
+    fn bfs_only_visits_set_points_of_hash_grid() {
+        let mut grid = HashGrid::new();
+        for x in 0..5 {
+            grid.set(&Point::new(x, 0), ());
+        }
+        grid.set(&Point::new(4, 2), ());
+
+        let result = bfs(&grid, Point::new(0, 0), &Point::DIRECTIONS_4, |_, _, _| {
+            true
+        });
+
+        assert_eq!(result.distances.len(), 5);
+        assert_eq!(result.distance(&Point::new(4, 0)), Some(4));
+        assert_eq!(result.distance(&Point::new(4, 2)), None);
+    }
+
+    #[rstest]
+    fn dijkstra_uses_weights() {
+        let grid = VecGrid::parse(
+            "
+11111
+99991
+11111
+19999
+11111
+",
+            |c| c.to_digit(10).unwrap() as u64,
+        );
+
+        let result = dijkstra(&grid, Point::new(0, 0), &Point::DIRECTIONS_4, |_, _, v| {
+            Some(*v)
+        });
+
+        assert_eq!(result.distance(&Point::new(4, 4)), Some(16));
+        assert_eq!(
+            result.path_to(&Point::new(4, 1)).unwrap(),
+            vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(3, 0),
+                Point::new(4, 0),
+                Point::new(4, 1)
+            ]
+        );
+    }
+
+    #[rstest]
+    fn astar_matches_dijkstra() {
+        let grid = VecGrid::parse(
+            "
+13119
+11911
+99111
+11191
+19111
+",
+            |c| c.to_digit(10).unwrap() as u64,
+        );
+        let start = Point::new(0, 0);
+        let goal = Point::new(4, 4);
+
+        let expected = dijkstra(&grid, start, &Point::DIRECTIONS_4, |_, _, v| Some(*v));
+        let (cost, path) = astar(
+            &grid,
+            start,
+            goal,
+            &Point::DIRECTIONS_4,
+            |_, _, v| Some(*v),
+            |p| p.distance(&goal) as u64,
+        )
+        .unwrap();
+
+        assert_eq!(Some(cost), expected.distance(&goal));
+        assert_eq!(
+            path.iter()
+                .skip(1)
+                .map(|p| grid.get(p).unwrap())
+                .sum::<u64>(),
+            cost
+        );
+    }
+
+    #[rstest]
+    fn astar_returns_none_when_unreachable() {
+        let grid = CharGrid::new("..#.\n..#.");
+        let goal = Point::new(3, 0);
+
+        let result = astar(
+            &grid,
+            Point::new(0, 0),
+            goal,
+            &Point::DIRECTIONS_8,
+            |_, _, c| (c != '#').then_some(1),
+            |p| p.chebyshev_distance(&goal) as u64,
+        );
+
+        assert_eq!(result, None);
+    }
+
+    #[rstest]
+    fn astar_matches_dijkstra_with_diagonals() {
+        let grid = CharGrid::new(
+            "
+.....
+.###.
+...#.
+.#...
+.....
+",
+        );
+        let start = Point::new(0, 4);
+        let goal = Point::new(4, 0);
+        let cost = |_: &Point, _: &Point, c: char| (c != '#').then_some(1);
+
+        let expected = dijkstra(&grid, start, &Point::DIRECTIONS_8, cost);
+        let (total, path) = astar(&grid, start, goal, &Point::DIRECTIONS_8, cost, |p| {
+            p.chebyshev_distance(&goal) as u64
+        })
+        .unwrap();
+
+        assert_eq!(Some(total), expected.distance(&goal));
+        assert_eq!(path.len() as u64, total + 1);
+    }
+}
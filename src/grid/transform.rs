@@ -0,0 +1,431 @@
+use std::convert::Infallible;
+
+use crate::components::Point;
+
+use super::Grid;
+
+/// Rotations and reflections of a grid. Every transformation returns a new grid of the same kind
+/// and rotating in steps of 90 degrees is always clockwise.
+///
+/// Points are handled relative to the lower bound of the grid, which stays in place.
+///
+/// # Examples
+/// ```
+/// use advent_of_code::grid::{char_grid::CharGrid, transform::Transform};
+///
+/// let grid = CharGrid::new("ab\ncd\nef");
+///
+/// assert_eq!(grid.rotate_90(), CharGrid::new("eca\nfdb"));
+/// assert_eq!(grid.flip_horizontal(), CharGrid::new("ba\ndc\nfe"));
+/// assert_eq!(grid.transpose(), CharGrid::new("ace\nbdf"));
+/// ```
+pub trait Transform: Sized {
+    /// Width and height of the grid
+    fn dimensions(&self) -> (usize, usize);
+
+    /// Builds a grid of width by height, moving the value at every relative point p to map(p)
+    fn remap<F: Fn(Point) -> Point>(&self, width: usize, height: usize, map: F) -> Self;
+
+    fn rotate_90(&self) -> Self {
+        let (width, height) = self.dimensions();
+        let h = height as isize;
+
+        self.remap(height, width, |p| Point::new(h - 1 - p.y, p.x))
+    }
+
+    fn rotate_180(&self) -> Self {
+        let (width, height) = self.dimensions();
+        let (w, h) = (width as isize, height as isize);
+
+        self.remap(width, height, |p| Point::new(w - 1 - p.x, h - 1 - p.y))
+    }
+
+    fn rotate_270(&self) -> Self {
+        let (width, height) = self.dimensions();
+        let w = width as isize;
+
+        self.remap(height, width, |p| Point::new(p.y, w - 1 - p.x))
+    }
+
+    /// Mirrors the grid left to right
+    fn flip_horizontal(&self) -> Self {
+        let (width, height) = self.dimensions();
+        let w = width as isize;
+
+        self.remap(width, height, |p| Point::new(w - 1 - p.x, p.y))
+    }
+
+    /// Mirrors the grid top to bottom
+    fn flip_vertical(&self) -> Self {
+        let (width, height) = self.dimensions();
+        let h = height as isize;
+
+        self.remap(width, height, |p| Point::new(p.x, h - 1 - p.y))
+    }
+
+    /// Swaps rows and columns, mirroring the grid over its main diagonal
+    fn transpose(&self) -> Self {
+        let (width, height) = self.dimensions();
+
+        self.remap(height, width, |p| Point::new(p.y, p.x))
+    }
+
+    /// All 8 rotations and reflections of the grid, starting with the grid itself. Symmetric grids
+    /// produce duplicates
+    fn orientations(&self) -> Vec<Self> {
+        let flipped = self.flip_horizontal();
+
+        vec![
+            self.remap(self.dimensions().0, self.dimensions().1, |p| p),
+            self.rotate_90(),
+            self.rotate_180(),
+            self.rotate_270(),
+            flipped.rotate_90(),
+            flipped.rotate_180(),
+            flipped.rotate_270(),
+            flipped,
+        ]
+    }
+}
+
+/// A read-only rectangular window into another grid. Points are relative to the lower corner of
+/// the window, so the view always has its bounds at 0,0. Setting a point is ruled out by its
+/// uninhabited `SetItem`, use SubGridMut to write through to the grid
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::Point;
+/// use advent_of_code::grid::{Grid, char_grid::CharGrid, transform::SubGrid};
+///
+/// let grid = CharGrid::new("abc\ndef\nghi");
+/// let view = SubGrid::new(&grid, Point::new(1, 1), Point::new(2, 2));
+///
+/// assert_eq!(view.get(&Point::new(0, 0)), Some('e'));
+/// assert_eq!(view.get(&Point::new(2, 0)), None);
+/// assert_eq!(view.values().collect::<String>(), "efhi");
+/// ```
+pub struct SubGrid<'g, G> {
+    grid: &'g G,
+    lower: Point,
+    upper: Point,
+}
+
+impl<'g, G> SubGrid<'g, G> {
+    /// Creates a view of all points of `grid` within lower..=upper
+    /// Panics if upper lies before lower
+    pub fn new(grid: &'g G, lower: Point, upper: Point) -> SubGrid<'g, G> {
+        assert_window(lower, upper);
+
+        SubGrid { grid, lower, upper }
+    }
+}
+
+impl<'a, 'g: 'a, G: Grid<'a>> Grid<'a> for SubGrid<'g, G> {
+    type ReturnItem = G::ReturnItem;
+    type SetItem = Infallible;
+
+    fn bounds(&self) -> (Point, Point) {
+        (Point::new(0, 0), self.upper - self.lower)
+    }
+
+    fn get(&'a self, point: &Point) -> Option<Self::ReturnItem> {
+        if !self.in_bounds(point) {
+            return None;
+        }
+
+        self.grid.get(&(*point + self.lower))
+    }
+
+    fn set(&mut self, _point: &Point, value: Self::SetItem) {
+        match value {}
+    }
+
+    fn keys(&self) -> impl Iterator<Item = Point> {
+        let grid = self.grid;
+        let lower = self.lower;
+
+        window(self.lower, self.upper).filter(move |p| grid.get(&(*p + lower)).is_some())
+    }
+
+    fn values(&'a self) -> impl Iterator<Item = Self::ReturnItem> {
+        self.entries().map(|(_, v)| v)
+    }
+
+    fn entries(&'a self) -> impl Iterator<Item = (Point, Self::ReturnItem)> {
+        window(self.lower, self.upper)
+            .filter_map(|p| self.grid.get(&(p + self.lower)).map(|v| (p, v)))
+    }
+}
+
+/// A rectangular window into another grid like SubGrid, that writes through to the grid it
+/// borrows
+///
+/// # Examples
+/// ```
+/// use advent_of_code::components::Point;
+/// use advent_of_code::grid::{Grid, char_grid::CharGrid, transform::SubGridMut};
+///
+/// let mut grid = CharGrid::new("abc\ndef\nghi");
+/// let mut view = SubGridMut::new(&mut grid, Point::new(1, 1), Point::new(2, 2));
+///
+/// view.set(&Point::new(1, 1), 'x');
+/// assert_eq!(view.values().collect::<String>(), "efhx");
+/// assert_eq!(grid.get(&Point::new(2, 2)), Some('x'));
+/// ```
+pub struct SubGridMut<'g, G> {
+    grid: &'g mut G,
+    lower: Point,
+    upper: Point,
+}
+
+impl<'g, G> SubGridMut<'g, G> {
+    /// Creates a view of all points of `grid` within lower..=upper
+    /// Panics if upper lies before lower
+    pub fn new(grid: &'g mut G, lower: Point, upper: Point) -> SubGridMut<'g, G> {
+        assert_window(lower, upper);
+
+        SubGridMut { grid, lower, upper }
+    }
+}
+
+impl<'a, 'g: 'a, G: Grid<'a>> Grid<'a> for SubGridMut<'g, G> {
+    type ReturnItem = G::ReturnItem;
+    type SetItem = G::SetItem;
+
+    fn bounds(&self) -> (Point, Point) {
+        (Point::new(0, 0), self.upper - self.lower)
+    }
+
+    fn get(&'a self, point: &Point) -> Option<Self::ReturnItem> {
+        if !self.in_bounds(point) {
+            return None;
+        }
+
+        self.grid.get(&(*point + self.lower))
+    }
+
+    /// Panics if the point falls outside of the view
+    fn set(&mut self, point: &Point, value: Self::SetItem) {
+        assert!(self.in_bounds(point), "point {point} out of bounds");
+
+        self.grid.set(&(*point + self.lower), value);
+    }
+
+    fn keys(&self) -> impl Iterator<Item = Point> {
+        let (lower, upper) = (self.lower, self.upper);
+
+        self.grid
+            .keys()
+            .filter(move |p| {
+                (lower.x..=upper.x).contains(&p.x) && (lower.y..=upper.y).contains(&p.y)
+            })
+            .map(move |p| p - lower)
+    }
+
+    fn values(&'a self) -> impl Iterator<Item = Self::ReturnItem> {
+        self.entries().map(|(_, v)| v)
+    }
+
+    fn entries(&'a self) -> impl Iterator<Item = (Point, Self::ReturnItem)> {
+        window(self.lower, self.upper)
+            .filter_map(|p| self.grid.get(&(p + self.lower)).map(|v| (p, v)))
+    }
+}
+
+fn assert_window(lower: Point, upper: Point) {
+    assert!(
+        lower.x <= upper.x && lower.y <= upper.y,
+        "sub-grid {lower} to {upper} is empty"
+    );
+}
+
+/// Every point of a window relative to its lower corner, row by row
+fn window(lower: Point, upper: Point) -> impl Iterator<Item = Point> {
+    let size = upper - lower;
+
+    (0..=size.y).flat_map(move |y| (0..=size.x).map(move |x| Point::new(x, y)))
+}
+
+#[cfg(test)]
+mod test {
+    use itertools::Itertools;
+    use rstest::rstest;
+
+    use crate::grid::{char_grid::CharGrid, hash_grid::HashGrid, search::bfs, vec_grid::VecGrid};
+
+    use super::*;
+
+    fn shape() -> CharGrid {
+        CharGrid::new(
+            "
+##.
+#..
+###
+",
+        )
+    }
+
+    #[rstest]
+    fn rotates_clockwise() {
+        let grid = shape();
+
+        let expected = CharGrid::new(
+            "
+###
+#.#
+#..
+",
+        );
+
+        assert_eq!(grid.rotate_90(), expected);
+        assert_eq!(grid.rotate_90().rotate_90(), grid.rotate_180());
+        assert_eq!(grid.rotate_180().rotate_90(), grid.rotate_270());
+        assert_eq!(grid.rotate_270().rotate_90(), grid);
+    }
+
+    #[rstest]
+    fn flips_and_transposes() {
+        let grid = shape();
+
+        assert_eq!(grid.flip_horizontal(), CharGrid::new(".##\n..#\n###"));
+        assert_eq!(grid.flip_vertical(), CharGrid::new("###\n#..\n##."));
+        assert_eq!(grid.transpose(), grid.rotate_90().flip_horizontal());
+        assert_eq!(grid.flip_vertical().flip_vertical(), grid);
+    }
+
+    #[rstest]
+    fn transforms_non_square_vec_grids() {
+        let grid = VecGrid::parse("123\n456", |c| c.to_digit(10).unwrap());
+
+        let rotated = grid.rotate_90();
+        assert_eq!((rotated.width(), rotated.height()), (2, 3));
+        assert_eq!(rotated.row(0), &[4, 1]);
+        assert_eq!(rotated.row(2), &[6, 3]);
+
+        let transposed = grid.transpose();
+        assert_eq!(transposed.row(1), &[2, 5]);
+        assert_eq!(grid.rotate_270().rotate_90(), grid);
+    }
+
+    #[rstest]
+    fn transforms_hash_grids_in_place() {
+        let mut grid = HashGrid::with_bounds(Point::new(-1, 5), Point::new(1, 5));
+        grid.set(&Point::new(-1, 5), 'a');
+        grid.set(&Point::new(1, 5), 'c');
+
+        let rotated = grid.rotate_90();
+
+        assert_eq!(rotated.bounds(), (Point::new(-1, 5), Point::new(-1, 7)));
+        assert_eq!(rotated.get(&Point::new(-1, 5)), Some(&'a'));
+        assert_eq!(rotated.get(&Point::new(-1, 7)), Some(&'c'));
+        assert_eq!(rotated.values().count(), 2);
+    }
+
+    #[rstest]
+    fn lists_unique_orientations() {
+        let grid = shape();
+
+        let orientations = grid.orientations();
+
+        assert_eq!(orientations.len(), 8);
+        assert_eq!(orientations[0], grid);
+        assert_eq!(
+            orientations.iter().unique_by(|g| format!("{g:?}")).count(),
+            8
+        );
+
+        let square = CharGrid::new("##\n##");
+        assert_eq!(
+            square
+                .orientations()
+                .iter()
+                .unique_by(|g| format!("{g:?}"))
+                .count(),
+            1
+        );
+    }
+
+    #[rstest]
+    fn sub_grid_views_can_be_searched() {
+        let grid = CharGrid::new(
+            "
+#####
+#...#
+#.#.#
+#...#
+#####
+",
+        );
+        let view = SubGrid::new(&grid, Point::new(1, 1), Point::new(3, 3));
+
+        assert_eq!(view.bounds(), (Point::new(0, 0), Point::new(2, 2)));
+        assert_eq!(view.keys().count(), 9);
+        assert_eq!(view.find_by_value('#'), Some(Point::new(1, 1)));
+
+        let result = bfs(&view, Point::new(0, 0), &Point::DIRECTIONS_4, |_, _, c| {
+            c != '#'
+        });
+        assert_eq!(result.distance(&Point::new(2, 2)), Some(4));
+        assert_eq!(result.distances.len(), 8);
+    }
+
+    #[rstest]
+    fn sub_grid_views_skip_unset_points() {
+        let mut grid = HashGrid::new();
+        grid.set(&Point::new(2, 2), 1);
+        grid.set(&Point::new(3, 3), 2);
+        grid.set(&Point::new(9, 9), 3);
+
+        let view = SubGrid::new(&grid, Point::new(2, 2), Point::new(4, 4));
+        let other = SubGrid::new(&grid, Point::new(3, 3), Point::new(9, 9));
+
+        assert_eq!(
+            view.entries().collect_vec(),
+            vec![(Point::new(0, 0), &1), (Point::new(1, 1), &2)]
+        );
+        assert_eq!(other.keys().count(), 2);
+
+        let view = SubGridMut::new(&mut grid, Point::new(2, 2), Point::new(4, 4));
+        assert_eq!(
+            view.keys().sorted_by_key(|p| (p.y, p.x)).collect_vec(),
+            vec![Point::new(0, 0), Point::new(1, 1)]
+        );
+    }
+
+    #[rstest]
+    fn sub_grid_writes_through_to_grid() {
+        let mut grid = shape();
+
+        let mut view = SubGridMut::new(&mut grid, Point::new(1, 1), Point::new(2, 2));
+        view.set(&Point::new(0, 0), '#');
+        view.set(&Point::new(1, 0), '#');
+
+        assert_eq!(
+            grid,
+            CharGrid::new(
+                "
+##.
+###
+###
+",
+            )
+        );
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn sub_grid_should_panic_when_setting_outside_view() {
+        let mut grid = shape();
+
+        let mut view = SubGridMut::new(&mut grid, Point::new(1, 1), Point::new(2, 2));
+        view.set(&Point::new(2, 0), '#');
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn sub_grid_should_panic_when_empty() {
+        let grid = shape();
+
+        SubGrid::new(&grid, Point::new(2, 2), Point::new(1, 2));
+    }
+}
@@ -0,0 +1,330 @@
+use std::fmt::Debug;
+
+use itertools::Itertools;
+
+use crate::components::Point;
+
+use super::{Grid, char_grid::CharGrid, transform::Transform};
+
+/// A dense grid backed by a single row-major `Vec`. Bounds always start at 0,0 and every point
+/// within the bounds holds a value, making get and set constant time.
+#[derive(PartialEq, Eq, Clone)]
+pub struct VecGrid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> VecGrid<T> {
+    /// Creates a new VecGrid of width by height, filled with `value`
+    /// Panics if the width or height is zero
+    pub fn new(width: usize, height: usize, value: T) -> VecGrid<T>
+    where
+        T: Clone,
+    {
+        assert!(width != 0 && height != 0, "grid dimensions cannot be empty");
+
+        VecGrid {
+            data: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a new VecGrid from a string, mapping every char to a value using `convert_fn`.
+    /// Follows the same trimming and validation rules as CharGrid::new
+    ///
+    /// # Examples
+    /// ```
+    /// use advent_of_code::components::Point;
+    /// use advent_of_code::grid::{Grid, vec_grid::VecGrid};
+    ///
+    /// let grid = VecGrid::parse("#.\n.#", |c| c == '#');
+    /// assert_eq!(grid.get(&Point::new(1, 1)), Some(&true));
+    /// assert_eq!(grid.get(&Point::new(1, 0)), Some(&false));
+    /// ```
+    pub fn parse<F>(input: &str, convert_fn: F) -> VecGrid<T>
+    where
+        F: Fn(char) -> T,
+    {
+        Self::from_chargrid(&CharGrid::new(input), convert_fn)
+    }
+
+    /// Creates a new VecGrid from a list of rows
+    /// Panics if there are no rows, or if any of the rows differs in length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> VecGrid<T> {
+        let width = rows.first().map(|r| r.len()).unwrap_or(0);
+        assert!(width != 0, "grid dimensions cannot be empty");
+        assert!(
+            rows.iter().all(|r| r.len() == width),
+            "all rows must be of equal width"
+        );
+
+        VecGrid {
+            height: rows.len(),
+            data: rows.into_iter().flatten().collect(),
+            width,
+        }
+    }
+
+    pub fn from_chargrid<F>(grid: &CharGrid, convert_fn: F) -> VecGrid<T>
+    where
+        F: Fn(char) -> T,
+    {
+        let (_, upper) = grid.bounds();
+
+        VecGrid {
+            data: grid.values().map(convert_fn).collect(),
+            width: upper.x as usize + 1,
+            height: upper.y as usize + 1,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the values of row `y` as a slice
+    /// Panics if `y` falls outside of the grid
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} out of bounds");
+
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns the values of row `y` as a mutable slice
+    /// Panics if `y` falls outside of the grid
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(y < self.height, "row {y} out of bounds");
+
+        &mut self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns an iterator over all rows of the grid
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.data.chunks(self.width)
+    }
+
+    /// Returns the values of column `x`, top to bottom
+    /// Panics if `x` falls outside of the grid
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} out of bounds");
+
+        self.data.iter().skip(x).step_by(self.width)
+    }
+
+    fn index(&self, point: &Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok()?;
+        let y = usize::try_from(point.y).ok()?;
+
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        Some(y * self.width + x)
+    }
+
+    fn point(&self, index: usize) -> Point {
+        Point::new((index % self.width) as isize, (index / self.width) as isize)
+    }
+}
+
+impl<'a, T: 'a> Grid<'a> for VecGrid<T> {
+    type ReturnItem = &'a T;
+    type SetItem = T;
+
+    fn bounds(&self) -> (Point, Point) {
+        (
+            Point::new(0, 0),
+            Point::new(self.width as isize - 1, self.height as isize - 1),
+        )
+    }
+
+    fn get(&'a self, point: &Point) -> Option<Self::ReturnItem> {
+        self.index(point).map(|i| &self.data[i])
+    }
+
+    fn set(&mut self, point: &Point, value: Self::SetItem) {
+        let index = self
+            .index(point)
+            .unwrap_or_else(|| panic!("point {point} out of bounds"));
+
+        self.data[index] = value;
+    }
+
+    fn keys(&self) -> impl Iterator<Item = Point> {
+        (0..self.data.len()).map(|i| self.point(i))
+    }
+
+    fn values(&'a self) -> impl Iterator<Item = Self::ReturnItem> {
+        self.data.iter()
+    }
+
+    fn entries(&'a self) -> impl Iterator<Item = (Point, Self::ReturnItem)> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (self.point(i), v))
+    }
+}
+
+impl<T: Clone> Transform for VecGrid<T> {
+    fn dimensions(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    fn remap<F: Fn(Point) -> Point>(&self, width: usize, height: usize, map: F) -> Self {
+        let mut data = vec![None; self.data.len()];
+
+        for (i, value) in self.data.iter().enumerate() {
+            let target = map(self.point(i));
+            data[target.y as usize * width + target.x as usize] = Some(value.clone());
+        }
+
+        VecGrid {
+            data: data.into_iter().map(|v| v.unwrap()).collect(),
+            width,
+            height,
+        }
+    }
+}
+
+impl<T: Debug> Debug for VecGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.rows()
+                .map(|row| row.iter().map(|v| format!("{v:?}")).join(" "))
+                .join("\n")
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[should_panic]
+    fn new_should_fail_when_empty() {
+        VecGrid::new(0, 5, 0);
+    }
+
+    #[rstest]
+    fn basic_vecgrid_happy_flow() {
+        let mut grid = VecGrid::new(4, 3, 0);
+
+        assert_eq!(grid.bounds(), (Point::new(0, 0), Point::new(3, 2)));
+        assert_eq!(grid.width(), 4);
+        assert_eq!(grid.height(), 3);
+
+        assert!(grid.in_bounds(&Point::new(3, 2)));
+        assert!(!grid.in_bounds(&Point::new(4, 2)));
+
+        assert_eq!(grid.get(&Point::new(3, 2)), Some(&0));
+        assert_eq!(grid.get(&Point::new(4, 0)), None);
+        assert_eq!(grid.get(&Point::new(-1, 0)), None);
+
+        grid.set(&Point::new(2, 1), 5);
+        assert_eq!(grid.get(&Point::new(2, 1)), Some(&5));
+        assert_eq!(grid.get(&Point::new(1, 2)), Some(&0));
+
+        assert_eq!(grid.values().sum::<i32>(), 5);
+        assert_eq!(grid.find_by_value(&5), Some(Point::new(2, 1)));
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn set_should_panic_out_of_bounds() {
+        let mut grid = VecGrid::new(2, 2, 0);
+
+        grid.set(&Point::new(2, 0), 1);
+    }
+
+    #[rstest]
+    fn can_parse_with_mapping() {
+        let input = "
+1.3
+.5.
+"
+        .trim();
+
+        let grid = VecGrid::parse(input, |c| c.to_digit(10));
+
+        assert_eq!(grid.bounds(), (Point::new(0, 0), Point::new(2, 1)));
+        assert_eq!(grid.get(&Point::new(0, 0)), Some(&Some(1)));
+        assert_eq!(grid.get(&Point::new(1, 0)), Some(&None));
+        assert_eq!(grid.get(&Point::new(1, 1)), Some(&Some(5)));
+
+        let from_chargrid = VecGrid::from_chargrid(&CharGrid::new(input), |c| c.to_digit(10));
+        assert!(grid == from_chargrid);
+    }
+
+    #[rstest]
+    fn can_create_from_rows() {
+        let grid = VecGrid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(grid.bounds(), (Point::new(0, 0), Point::new(2, 1)));
+        assert_eq!(grid.get(&Point::new(0, 1)), Some(&4));
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn from_rows_should_fail_on_unequal_rows() {
+        VecGrid::from_rows(vec![vec![1, 2, 3], vec![4, 5]]);
+    }
+
+    #[rstest]
+    fn can_access_rows_and_columns() {
+        let mut grid = VecGrid::parse("abc\ndef\nghi", |c| c);
+
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cfi");
+        assert_eq!(grid.rows().count(), 3);
+
+        grid.row_mut(0)[1] = 'x';
+        assert_eq!(grid.get(&Point::new(1, 0)), Some(&'x'));
+        assert_eq!(grid.column(1).collect::<String>(), "xeh");
+    }
+
+    #[rstest]
+    fn keys_follow_row_major_order() {
+        let grid = VecGrid::new(2, 2, ());
+
+        assert_eq!(
+            grid.keys().collect_vec(),
+            vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(1, 1)
+            ]
+        );
+    }
+
+    #[rstest]
+    fn vec_grid_can_draw() {
+        let input = "
+#..
+.#.
+..#
+"
+        .trim();
+
+        let grid = VecGrid::parse(input, |c| c == '#');
+
+        let result = grid.draw(|_point, value| match value {
+            Some(true) => "#".to_string(),
+            _ => ".".to_string(),
+        });
+
+        assert_eq!(result, input);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod components;
 pub mod grid;
+pub mod parse;
 pub mod prelude;
 pub mod template;
@@ -1,6 +1,11 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{all, download, read, scaffold, solve, time, verify};
+use advent_of_code::template::registry::Registry;
 use args::{AppArguments, parse};
 
+// every day defines a global allocator for dhat, which can only exist once per binary.
+#[cfg(all(feature = "registry", not(feature = "dhat-heap")))]
+mod days;
+
 #[cfg(feature = "today")]
 use advent_of_code::template::Day;
 #[cfg(feature = "today")]
@@ -30,11 +35,19 @@ mod args {
         },
         All {
             release: bool,
+            parallel: bool,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            compare: bool,
+            threshold: f64,
+            parallel: bool,
+        },
+        Verify {
+            day: Option<Day>,
+            parallel: bool,
         },
         #[cfg(feature = "today")]
         Today,
@@ -46,15 +59,30 @@ mod args {
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                parallel: args.contains("--parallel"),
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let compare = args.contains("--compare");
+                let threshold = args.opt_value_from_str("--threshold")?.unwrap_or(10.0);
+                let parallel = args.contains("--parallel");
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    compare,
+                    threshold,
+                    parallel,
+                }
+            }
+            Some("verify") => {
+                let parallel = args.contains("--parallel");
+
+                AppArguments::Verify {
+                    day: args.opt_free_from_str()?,
+                    parallel,
                 }
             }
             Some("download") => AppArguments::Download {
@@ -95,6 +123,26 @@ mod args {
     }
 }
 
+/// Solutions to run in-process, only available when built with the `registry` feature.
+fn registry(parallel: bool) -> Option<Registry> {
+    #[cfg(all(feature = "registry", not(feature = "dhat-heap")))]
+    return Some(Registry {
+        solutions: days::SOLUTIONS,
+        parallel,
+    });
+
+    #[cfg(not(all(feature = "registry", not(feature = "dhat-heap"))))]
+    {
+        if parallel {
+            eprintln!(
+                "Warning: `--parallel` requires the `registry` feature, running days one by one."
+            );
+        }
+
+        None
+    }
+}
+
 fn main() {
     match parse() {
         Err(err) => {
@@ -102,8 +150,16 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::All { release, parallel } => all::handle(release, registry(parallel)),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                compare,
+                threshold,
+                parallel,
+            } => time::handle(day, all, store, compare, threshold, registry(parallel)),
+            AppArguments::Verify { day, parallel } => verify::handle(day, registry(parallel)),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
             AppArguments::Scaffold {
@@ -0,0 +1,495 @@
+//! Small parser combinators for puzzle inputs. Every parser is a function over a Cursor, so they
+//! compose by passing them to each other, and failures report the line and column they happened
+//! at instead of panicking on an unwrap.
+//!
+//! # Examples
+//! ```
+//! use advent_of_code::prelude::*;
+//!
+//! let input = "a: 1 2 3\nb: 4 -5";
+//! let line = parse::key_value(parse::word(), parse::separated(parse::signed::<i64>(), " "));
+//!
+//! assert_eq!(
+//!     parse::parse_lines(input, &line),
+//!     Ok(vec![("a", vec![1, 2, 3]), ("b", vec![4, -5])])
+//! );
+//!
+//! let error = parse::parse_lines("a: 1 2\nb: 3 x", &line).unwrap_err();
+//! assert_eq!(error.to_string(), "line 2, column 6: expected a number, found 'x'");
+//! ```
+
+use std::{fmt::Display, str::FromStr};
+
+/// A parse failure, with the 1-based line and column it happened at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+/// A position in the input. Cursors for a single line or block still point into the full input,
+/// so errors always report the position within the whole input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor<'a> {
+    source: &'a str,
+    pos: usize,
+    end: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(source: &'a str) -> Cursor<'a> {
+        Cursor {
+            source,
+            pos: 0,
+            end: source.len(),
+        }
+    }
+
+    /// The input left to parse
+    pub fn rest(&self) -> &'a str {
+        &self.source[self.pos..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.end
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Consumes and returns the next char
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+
+        Some(c)
+    }
+
+    /// Consumes chars while `predicate` holds, returning them
+    pub fn take_while<F: Fn(char) -> bool>(&mut self, predicate: F) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.pos += len;
+
+        &rest[..len]
+    }
+
+    /// Line and column of the cursor, both starting at 1
+    pub fn position(&self) -> (usize, usize) {
+        let before = &self.source[..self.pos];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        (
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        )
+    }
+
+    /// An error at the current position
+    pub fn error<S: Into<String>>(&self, message: S) -> ParseError {
+        let (line, column) = self.position();
+
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// An error saying what was expected and what was found instead
+    pub fn expected(&self, expected: &str) -> ParseError {
+        match self.peek() {
+            Some('\n') => self.error(format!("expected {expected}, found end of line")),
+            Some(c) => self.error(format!("expected {expected}, found '{c}'")),
+            None => self.error(format!("expected {expected}, found end of input")),
+        }
+    }
+}
+
+/// Anything that parses a value from a Cursor. Implemented for all matching closures and
+/// functions, which is what every combinator returns
+pub trait Parser<'a, T>: Fn(&mut Cursor<'a>) -> ParseResult<T> {}
+
+impl<'a, T, F> Parser<'a, T> for F where F: Fn(&mut Cursor<'a>) -> ParseResult<T> {}
+
+/// Runs a parser over the whole input, ignoring trailing whitespace. Fails if anything is left
+pub fn parse_all<'a, T>(input: &'a str, parser: impl Parser<'a, T>) -> ParseResult<T> {
+    let mut cursor = Cursor::new(input.trim_end());
+    let value = parser(&mut cursor)?;
+
+    if !cursor.is_empty() {
+        return Err(cursor.expected("end of input"));
+    }
+
+    Ok(value)
+}
+
+/// Runs a parser over every line of the input
+pub fn parse_lines<'a, T>(input: &'a str, parser: impl Parser<'a, T>) -> ParseResult<Vec<T>> {
+    parse_all(input, lines(parser))
+}
+
+/// Matches an exact string
+pub fn tag<'a, 's>(expected: &'s str) -> impl Parser<'a, &'a str> {
+    move |cursor: &mut Cursor<'a>| {
+        let rest = cursor.rest();
+        if !rest.starts_with(expected) {
+            return Err(cursor.expected(&format!("'{expected}'")));
+        }
+
+        cursor.pos += expected.len();
+        Ok(&rest[..expected.len()])
+    }
+}
+
+/// Matches a single char from `chars`
+pub fn one_of<'a, 's>(chars: &'s str) -> impl Parser<'a, char> {
+    move |cursor: &mut Cursor<'a>| match cursor.peek() {
+        Some(c) if chars.contains(c) => {
+            cursor.next_char();
+            Ok(c)
+        }
+        _ => Err(cursor.expected(&format!("one of '{chars}'"))),
+    }
+}
+
+/// Skips any spaces and tabs, always succeeds
+pub fn spaces<'a>() -> impl Parser<'a, ()> {
+    |cursor: &mut Cursor<'a>| {
+        cursor.take_while(|c| c == ' ' || c == '\t');
+        Ok(())
+    }
+}
+
+/// A run of letters, digits and underscores
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    |cursor: &mut Cursor<'a>| {
+        let start = *cursor;
+        let word = cursor.take_while(|c| c.is_alphanumeric() || c == '_');
+
+        if word.is_empty() {
+            return Err(start.expected("a word"));
+        }
+
+        Ok(word)
+    }
+}
+
+fn number<'a, T: FromStr>(
+    cursor: &mut Cursor<'a>,
+    text: &'a str,
+    start: Cursor<'a>,
+) -> ParseResult<T> {
+    if !text.ends_with(|c: char| c.is_ascii_digit()) {
+        *cursor = start;
+        return Err(start.expected("a number"));
+    }
+
+    text.parse()
+        .map_err(|_| start.error(format!("{text} does not fit the number type")))
+}
+
+/// An unsigned integer, made of digits only
+pub fn unsigned<'a, T: FromStr>() -> impl Parser<'a, T> {
+    |cursor: &mut Cursor<'a>| {
+        let start = *cursor;
+        let digits = cursor.take_while(|c| c.is_ascii_digit());
+
+        number(cursor, digits, start)
+    }
+}
+
+/// An integer with an optional leading + or -
+pub fn signed<'a, T: FromStr>() -> impl Parser<'a, T> {
+    |cursor: &mut Cursor<'a>| {
+        let start = *cursor;
+        if matches!(cursor.peek(), Some('-' | '+')) {
+            cursor.next_char();
+        }
+        cursor.take_while(|c| c.is_ascii_digit());
+
+        number(cursor, &start.rest()[..cursor.pos - start.pos], start)
+    }
+}
+
+/// Transforms the value of a parser
+pub fn map<'a, T, U, P, F>(parser: P, f: F) -> impl Parser<'a, U>
+where
+    P: Parser<'a, T>,
+    F: Fn(T) -> U,
+{
+    move |cursor: &mut Cursor<'a>| parser(cursor).map(&f)
+}
+
+/// Runs two parsers after each other, keeping both values
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |cursor: &mut Cursor<'a>| Ok((first(cursor)?, second(cursor)?))
+}
+
+/// Runs two parsers after each other, keeping the value of the second
+pub fn preceded<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, B> {
+    move |cursor: &mut Cursor<'a>| {
+        first(cursor)?;
+        second(cursor)
+    }
+}
+
+/// Runs two parsers after each other, keeping the value of the first
+pub fn terminated<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, A> {
+    move |cursor: &mut Cursor<'a>| {
+        let value = first(cursor)?;
+        second(cursor)?;
+
+        Ok(value)
+    }
+}
+
+/// A parser wrapped in an opening and closing string, like `[.##.]` or `{3,5}`
+pub fn bracketed<'a, 's, T>(
+    open: &'s str,
+    parser: impl Parser<'a, T>,
+    close: &'s str,
+) -> impl Parser<'a, T> {
+    preceded(tag(open), terminated(parser, tag(close)))
+}
+
+/// Tries a parser, rewinding and returning None if it fails
+pub fn optional<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |cursor: &mut Cursor<'a>| {
+        let start = *cursor;
+        match parser(cursor) {
+            Ok(value) => Ok(Some(value)),
+            Err(_) => {
+                *cursor = start;
+                Ok(None)
+            }
+        }
+    }
+}
+
+/// Runs a parser as often as it succeeds, zero times included
+pub fn many<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |cursor: &mut Cursor<'a>| {
+        let mut values = vec![];
+
+        loop {
+            let start = *cursor;
+            match parser(cursor) {
+                Ok(value) => values.push(value),
+                Err(_) => {
+                    *cursor = start;
+                    break;
+                }
+            }
+
+            // A parser that succeeds without consuming anything would match forever
+            if cursor.pos == start.pos {
+                break;
+            }
+        }
+
+        Ok(values)
+    }
+}
+
+/// One or more values separated by `separator`, like `1,2,3`
+pub fn separated<'a, 's, T>(
+    parser: impl Parser<'a, T>,
+    separator: &'s str,
+) -> impl Parser<'a, Vec<T>> {
+    move |cursor: &mut Cursor<'a>| {
+        let mut values = vec![parser(cursor)?];
+        while cursor.rest().starts_with(separator) {
+            cursor.pos += separator.len();
+            values.push(parser(cursor)?);
+        }
+
+        Ok(values)
+    }
+}
+
+/// A `key: value` line, with any spaces after the colon skipped
+pub fn key_value<'a, K, V>(
+    key: impl Parser<'a, K>,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (K, V)> {
+    pair(terminated(key, pair(tag(":"), spaces())), value)
+}
+
+/// Runs a parser on every line until the input or the current block ends. Every line has to be
+/// consumed completely
+pub fn lines<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |cursor: &mut Cursor<'a>| {
+        let mut values = vec![];
+
+        loop {
+            values.push(parser(cursor)?);
+
+            if cursor.is_empty() || cursor.rest().starts_with("\n\n") {
+                break;
+            }
+            if cursor.peek() != Some('\n') {
+                return Err(cursor.expected("end of line"));
+            }
+            cursor.next_char();
+        }
+
+        Ok(values)
+    }
+}
+
+/// Runs a parser on every block of input separated by a blank line. Every block has to be
+/// consumed completely
+pub fn blocks<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |cursor: &mut Cursor<'a>| {
+        let mut values = vec![];
+
+        loop {
+            let end = cursor
+                .rest()
+                .find("\n\n")
+                .map_or(cursor.end, |i| cursor.pos + i);
+            let mut block = Cursor { end, ..*cursor };
+
+            values.push(parser(&mut block)?);
+            if !block.is_empty() {
+                return Err(block.expected("end of block"));
+            }
+
+            cursor.pos = end;
+            if cursor.is_empty() {
+                break;
+            }
+            cursor.pos += cursor.rest().len() - cursor.rest().trim_start_matches('\n').len();
+        }
+
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("42", Ok(42))]
+    #[case("-42", Ok(-42))]
+    #[case("+7", Ok(7))]
+    #[case("-", Err("line 1, column 1: expected a number, found '-'"))]
+    #[case("x", Err("line 1, column 1: expected a number, found 'x'"))]
+    #[case("300", Err("line 1, column 1: 300 does not fit the number type"))]
+    fn it_parses_signed_numbers(#[case] input: &str, #[case] expected: Result<i8, &str>) {
+        assert_eq!(
+            parse_all(input, signed::<i8>()).map_err(|e| e.to_string()),
+            expected.map_err(|e| e.to_string())
+        );
+    }
+
+    #[rstest]
+    fn it_parses_separated_lists() {
+        let ranges = separated(
+            map(
+                pair(terminated(unsigned::<u64>(), tag("-")), unsigned()),
+                |(a, b)| a..=b,
+            ),
+            ",",
+        );
+
+        assert_eq!(
+            parse_all("11-22,95-115\n", &ranges),
+            Ok(vec![11..=22, 95..=115])
+        );
+
+        let error = parse_all("11-22,95_115", &ranges).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 9));
+    }
+
+    #[rstest]
+    fn it_parses_blocks_of_lines() {
+        let shapes = blocks(pair(
+            terminated(unsigned::<usize>(), tag(":")),
+            preceded(
+                tag("\n"),
+                lines(map(many(one_of("#.")), |cells| {
+                    cells.iter().filter(|c| **c == '#').count()
+                })),
+            ),
+        ));
+
+        assert_eq!(
+            parse_all("0:\n###\n#..\n\n\n1:\n.#.\n", &shapes),
+            Ok(vec![(0, vec![3, 1]), (1, vec![1])])
+        );
+
+        let error = parse_all("0:\n###\n\n1:\n.#.\n\n4x4: 0 1", &shapes).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 7, column 2: expected ':', found 'x'"
+        );
+    }
+
+    #[rstest]
+    fn it_parses_day_10_lines() {
+        let numbers = || separated(unsigned::<usize>(), ",");
+        let line = pair(
+            bracketed("[", many(map(one_of(".#"), |c| c == '#')), "]"),
+            pair(
+                many(preceded(spaces(), bracketed("(", numbers(), ")"))),
+                preceded(spaces(), bracketed("{", numbers(), "}")),
+            ),
+        );
+
+        let parsed = parse_lines("[.##.] (3) (1,3) {3,5}\n[#] {1}", &line).unwrap();
+        assert_eq!(
+            parsed[0],
+            (
+                vec![false, true, true, false],
+                (vec![vec![3], vec![1, 3]], vec![3, 5])
+            )
+        );
+        assert_eq!(parsed[1], (vec![true], (vec![], vec![1])));
+
+        let error = parse_lines("[.#] (1)\n[#] (1,) {1}", &line).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 9: expected '{', found end of line"
+        );
+        let error = parse_lines("[.#] (1) {1}\n[#] (1,) {1}", &line).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+    }
+
+    #[rstest]
+    fn it_parses_optional_values() {
+        let parser = pair(optional(terminated(word(), tag("="))), unsigned::<u32>());
+
+        assert_eq!(parse_all("x=5", &parser), Ok((Some("x"), 5)));
+        assert_eq!(parse_all("5", &parser), Ok((None, 5)));
+    }
+}
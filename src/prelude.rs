@@ -1,2 +1,3 @@
+pub use crate::parse::{self, ParseError, Parser};
 pub use itertools::Itertools;
 pub use rayon::prelude::*;
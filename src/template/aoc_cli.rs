@@ -46,7 +46,7 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
         day,
     );
 
-    call_aoc_cli(&args)
+    call_aoc_cli(&args, false)
 }
 
 pub fn download(day: Day) -> Result<Output, AocCommandError> {
@@ -65,7 +65,7 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
         day,
     );
 
-    let output = call_aoc_cli(&args)?;
+    let output = call_aoc_cli(&args, false)?;
     println!("---");
     println!("🎄 Successfully wrote input to \"{}\".", &input_path);
     println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
@@ -77,7 +77,14 @@ pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandErro
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    // capture the response to tell whether the answer was accepted.
+    call_aoc_cli(&args, true)
+}
+
+/// Whether the response to a submit says the answer was right.
+pub fn is_accepted(output: &Output) -> bool {
+    String::from_utf8_lossy(&output.stdout).contains("That's the right answer")
 }
 
 fn get_input_path(day: Day) -> String {
@@ -108,15 +115,27 @@ fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
     cmd_args
 }
 
-fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
+/// With `capture_stdout`, stdout is collected in the returned output and echoed once the command
+/// finishes, instead of being inherited.
+fn call_aoc_cli(args: &[String], capture_stdout: bool) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
+    let stdout = if capture_stdout {
+        Stdio::piped()
+    } else {
+        Stdio::inherit()
+    };
+
     let output = Command::new("aoc")
         .args(args)
-        .stdout(Stdio::inherit())
+        .stdout(stdout)
         .stderr(Stdio::inherit())
         .output()
         .map_err(|_| AocCommandError::CommandNotCallable)?;
 
+    if capture_stdout {
+        print!("{}", String::from_utf8_lossy(&output.stdout));
+    }
+
     if output.status.success() {
         Ok(output)
     } else {
@@ -1,5 +1,6 @@
+use crate::template::registry::Registry;
 use crate::template::{all_days, run_multi::run_multi};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(is_release: bool, registry: Option<Registry>) {
+    run_multi(&all_days().collect(), is_release, false, registry);
 }
@@ -1,5 +1,5 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
     process,
 };
@@ -27,10 +27,34 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
+/// Adds the day to the solutions compiled in with the `registry` feature, if not there yet.
+fn register_day(path: &str, day: Day) -> Result<bool, std::io::Error> {
+    let days = fs::read_to_string(path)?;
+    let module_path = format!("\"bin/{day}.rs\"");
+
+    if days.contains(&module_path) {
+        return Ok(false);
+    }
+
+    let Some(end) = days.rfind('}') else {
+        return Err(std::io::Error::other(
+            "could not find the end of the day list",
+        ));
+    };
+
+    let mut updated = days[..end].to_string();
+    updated.push_str(&format!("    day{day} => {module_path},\n"));
+    updated.push_str(&days[end..]);
+
+    fs::write(path, updated)?;
+    Ok(true)
+}
+
 pub fn handle(day: Day, overwrite: bool) {
     let input_path = format!("data/inputs/{day}.txt");
     let example_path = format!("data/examples/{day}.txt");
     let module_path = format!("src/bin/{day}.rs");
+    let days_path = "src/days.rs";
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -74,6 +98,17 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
+    match register_day(days_path, day) {
+        Ok(true) => {
+            println!("Registered day in \"{days_path}\"");
+        }
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to register day: {e}");
+            process::exit(1);
+        }
+    }
+
     println!("---");
     println!("🎄 Type `cargo solve {day}` to run your solution.");
 }
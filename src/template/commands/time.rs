@@ -1,15 +1,38 @@
 use std::collections::HashSet;
+use std::process;
 
+use crate::template::history::{self, History};
+use crate::template::registry::Registry;
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, all_days, readme_benchmarks};
+
+/// Benches the given day, or all days that are not fully benched yet.
+/// With `compare`, every part is checked against the last stored run, exiting with an error if
+/// any median got more than `threshold` percent slower. Runs are only stored with `store`.
+/// Parallel runs skew timings, so they are refused together with `store` or `compare`.
+pub fn handle(
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    compare: bool,
+    threshold: f64,
+    registry: Option<Registry>,
+) {
+    if (store || compare) && registry.is_some_and(|registry| registry.parallel) {
+        eprintln!("`--parallel` cannot be combined with `--store` or `--compare`.");
+        process::exit(1);
+    }
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
     let stored_timings = Timings::read_from_file();
+    let mut history = History::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to read benchmark history: {e}");
+        process::exit(1);
+    });
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
+            if run_all || compare {
                 all_days().collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
@@ -21,12 +44,25 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(&days_to_run, true, true, registry).unwrap();
+
+    let regressions = if compare {
+        history.compare(&timings, threshold)
+    } else {
+        vec![]
+    };
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
+        history.record(
+            &timings,
+            history::now(),
+            history::current_commit().as_deref(),
+        );
+        history.store_file().unwrap();
+
         println!();
         match readme_benchmarks::update(merged_timings) {
             Ok(()) => {
@@ -37,4 +73,28 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if compare {
+        println!();
+        if regressions.is_empty() {
+            println!("No regressions beyond {threshold}%.");
+            return;
+        }
+
+        println!("{ANSI_BOLD}Regressions beyond {threshold}%:{ANSI_RESET}");
+        for regression in &regressions {
+            let previous = &regression.previous;
+            println!(
+                "Day {} Part {}: {:.0}ns -> {:.0}ns (+{:.1}%) since {}",
+                regression.day,
+                regression.part,
+                previous.stats.median,
+                regression.median,
+                regression.slowdown(),
+                previous.commit.as_deref().unwrap_or("last run")
+            );
+        }
+
+        process::exit(1);
+    }
 }
@@ -0,0 +1,93 @@
+use std::process;
+
+use rayon::prelude::*;
+
+use crate::template::answers::{ANSWERS_FILE_PATH, Answers, Verdict};
+use crate::template::registry::Registry;
+use crate::template::run_multi::child_commands;
+use crate::template::runner::PartOutput;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day};
+
+/// Answers spanning multiple lines are shown by their first line only.
+fn short(answer: &str) -> String {
+    match answer.split_once('\n') {
+        Some((first, _)) => format!("{first}…"),
+        None => answer.to_string(),
+    }
+}
+
+fn format_cell(verdict: &Verdict, actual: Option<&str>) -> String {
+    let actual = actual.map_or_else(|| "✖".to_string(), short);
+
+    match verdict {
+        Verdict::Pass => format!("✔ {actual}"),
+        Verdict::Fail { expected } => format!("✖ {actual} (expected {})", short(expected)),
+        Verdict::Unsolved { expected } => format!("✖ unsolved (expected {})", short(expected)),
+        Verdict::Unrecorded => format!("- {actual}"),
+    }
+}
+
+fn run_day(day: Day, registry: Option<Registry>) -> Vec<PartOutput> {
+    match registry.and_then(|r| r.get(day).copied()) {
+        Some(solution) => solution.run(false),
+        None => child_commands::run_solution(day, false, true, false).unwrap(),
+    }
+}
+
+/// Runs the given day, or every day with accepted answers, checking their answers.
+/// Exits with an error if any part no longer returns its accepted answer.
+pub fn handle(day: Option<Day>, registry: Option<Registry>) {
+    let answers = Answers::read_from_file().unwrap_or_else(|e| {
+        eprintln!("Failed to read \"{ANSWERS_FILE_PATH}\": {e}");
+        process::exit(1);
+    });
+
+    let days = day.map_or_else(
+        || answers.data.iter().map(|a| a.day).collect::<Vec<_>>(),
+        |day| vec![day],
+    );
+
+    if days.is_empty() {
+        println!(
+            "No answers recorded yet. Answers are recorded after a successful `--submit`, \
+            or can be added to \"{ANSWERS_FILE_PATH}\"."
+        );
+        return;
+    }
+
+    let outputs: Vec<Vec<PartOutput>> = if registry.is_some_and(|r| r.parallel) {
+        days.par_iter().map(|day| run_day(*day, registry)).collect()
+    } else {
+        days.iter().map(|day| run_day(*day, registry)).collect()
+    };
+
+    let mut failures = 0;
+
+    println!("{ANSI_BOLD}| Day | Part 1 | Part 2 |{ANSI_RESET}");
+
+    for (day, output) in days.iter().zip(outputs) {
+        let cells = [1, 2].map(|part| {
+            let actual = output
+                .iter()
+                .find(|o| o.part == part)
+                .and_then(|o| o.answer.as_deref());
+            let verdict = answers.check(*day, part, actual);
+
+            if verdict.is_failure() {
+                failures += 1;
+            }
+
+            format_cell(&verdict, actual)
+        });
+
+        println!("| {day} | {} | {} |", cells[0], cells[1]);
+    }
+
+    println!();
+    if failures == 0 {
+        println!("All recorded answers match.");
+    } else {
+        println!("{failures} part(s) no longer return their accepted answer.");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,361 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    path::Path,
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::Day;
+use crate::template::timings::{PartStats, Timings};
+
+static HISTORY_FILE_PATH: &str = "./data/timings_history.json";
+
+/// A single benchmark measurement of one part, as recorded by `cargo time --store`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    pub day: Day,
+    pub part: u8,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the checked out commit, if the run happened inside a git repository.
+    pub commit: Option<String>,
+    pub stats: PartStats,
+}
+
+/// A part whose median got slower than the previously recorded run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Regression {
+    pub day: Day,
+    pub part: u8,
+    pub previous: Entry,
+    pub median: f64,
+}
+
+impl Regression {
+    /// Slowdown relative to the previous run, as a percentage.
+    pub fn slowdown(&self) -> f64 {
+        (self.median / self.previous.stats.median - 1.0) * 100.0
+    }
+}
+
+/// Every benchmark measurement ever stored, oldest first.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub entries: Vec<Entry>,
+}
+
+impl History {
+    /// Dehydrate history to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(HISTORY_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate history from a JSON file. If not present, returns an empty history.
+    /// Errors if the file cannot be read or parsed, so it is never overwritten by mistake.
+    pub fn read_from_file() -> Result<Self, String> {
+        History::read_from(Path::new(HISTORY_FILE_PATH))
+    }
+
+    fn read_from(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(json) => History::try_from(json),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Appends every benched part of `timings` as measured at `timestamp` on `commit`.
+    pub fn record(&mut self, timings: &Timings, timestamp: u64, commit: Option<&str>) {
+        for timing in &timings.data {
+            for (part, stats) in [(1, timing.part_1_stats), (2, timing.part_2_stats)] {
+                let Some(stats) = stats else {
+                    continue;
+                };
+
+                self.entries.push(Entry {
+                    day: timing.day,
+                    part,
+                    timestamp,
+                    commit: commit.map(String::from),
+                    stats,
+                });
+            }
+        }
+    }
+
+    /// The most recently recorded measurement of a part.
+    pub fn latest(&self, day: Day, part: u8) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .filter(|e| e.day == day && e.part == part)
+            .max_by_key(|e| e.timestamp)
+    }
+
+    /// Compares `timings` with the latest recorded run of every part, returning the parts whose
+    /// median is more than `threshold` percent slower. Parts without history are skipped.
+    pub fn compare(&self, timings: &Timings, threshold: f64) -> Vec<Regression> {
+        timings
+            .data
+            .iter()
+            .flat_map(|t| [(t.day, 1, t.part_1_stats), (t.day, 2, t.part_2_stats)])
+            .filter_map(|(day, part, stats)| {
+                let previous = self.latest(day, part)?;
+                let regression = Regression {
+                    day,
+                    part,
+                    previous: previous.clone(),
+                    median: stats?.median,
+                };
+
+                (regression.slowdown() > threshold).then_some(regression)
+            })
+            .collect()
+    }
+}
+
+/// Seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Short hash of the checked out commit. None outside of a git repository or without git.
+pub fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8(output.stdout)
+        .ok()
+        .map(|hash| hash.trim().to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<History> for JsonValue {
+    fn from(value: History) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "entries".into(),
+            JsonValue::Array(value.entries.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for History {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_entries = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("entries")
+            .ok_or("expected JSON document to have key `entries`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.entries` to be an array.")?;
+
+        Ok(History {
+            entries: json_entries
+                .iter()
+                .map(Entry::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Entry> for JsonValue {
+    fn from(value: &Entry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            match &value.commit {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("stats".into(), JsonValue::from(value.stats));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Entry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected entry.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .filter(|part| (1..=2).contains(part))
+            .ok_or("Expected entry.part to be 1 or 2.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|timestamp| *timestamp as u64)
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected entry.commit to be null or string.")?;
+
+        let stats = json
+            .get("stats")
+            .ok_or("Expected entry.stats to be present.")
+            .map(PartStats::try_from)??;
+
+        Ok(Entry {
+            day,
+            part,
+            timestamp,
+            commit: commit.cloned(),
+            stats,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use crate::{
+        day,
+        template::timings::{PartStats, Timing, Timings},
+    };
+
+    use super::History;
+
+    fn stats(median: f64) -> PartStats {
+        PartStats {
+            median,
+            min: median,
+            p95: median,
+            std_dev: 0.0,
+            samples: 10,
+            outliers: 0,
+        }
+    }
+
+    fn get_mock_timings(part_1: f64, part_2: f64) -> Timings {
+        Timings {
+            data: vec![Timing {
+                day: day!(1),
+                part_1: Some(format!("{part_1}ns")),
+                part_2: Some(format!("{part_2}ns")),
+                part_1_stats: Some(stats(part_1)),
+                part_2_stats: Some(stats(part_2)),
+                total_nanos: part_1 + part_2,
+            }],
+        }
+    }
+
+    #[test]
+    fn records_benched_parts() {
+        let mut history = History::default();
+        history.record(&get_mock_timings(100.0, 200.0), 10, Some("abc1234"));
+        history.record(&get_mock_timings(150.0, 250.0), 20, None);
+
+        assert_eq!(history.entries.len(), 4);
+
+        let latest = history.latest(day!(1), 2).unwrap();
+        assert_eq!(latest.timestamp, 20);
+        assert_eq!(latest.stats.median, 250.0);
+        assert_eq!(history.latest(day!(2), 1), None);
+    }
+
+    #[test]
+    fn flags_regressions_beyond_threshold() {
+        let mut history = History::default();
+        history.record(&get_mock_timings(100.0, 200.0), 10, None);
+
+        let regressions = history.compare(&get_mock_timings(120.0, 210.0), 10.0);
+
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].part, 1);
+        assert!((regressions[0].slowdown() - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut history = History::default();
+        history.record(
+            &get_mock_timings(100.0, 200.0),
+            1_700_000_000,
+            Some("abc1234"),
+        );
+
+        let json = tinyjson::JsonValue::from(history.clone())
+            .stringify()
+            .unwrap();
+        let parsed = History::try_from(json).unwrap();
+
+        assert_eq!(parsed.entries, history.entries);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_entries() {
+        let json = r#"{ "entries": [{ "day": "01", "part": 3, "timestamp": 0, "commit": null }] }"#;
+        History::try_from(json.to_string()).unwrap();
+    }
+
+    #[test]
+    fn reports_malformed_files() {
+        let dir = env::temp_dir().join(format!("aoc-history-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let missing = dir.join("missing.json");
+        assert!(History::read_from(&missing).unwrap().entries.is_empty());
+
+        let malformed = dir.join("malformed.json");
+        fs::write(&malformed, r#"{ "entries": [{ "day": "01" }] }"#).unwrap();
+        let result = History::read_from(&malformed);
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(result.is_err());
+    }
+}
@@ -2,11 +2,14 @@ use std::{env, fs};
 
 pub mod aoc_cli;
 pub mod commands;
+pub mod registry;
 pub mod runner;
 
 pub use day::*;
 
+mod answers;
 mod day;
+mod history;
 mod readme_benchmarks;
 mod run_multi;
 mod timings;
@@ -64,5 +67,13 @@ macro_rules! solution {
             let input = $crate::template::read_file("inputs", DAY);
             $( run_part($func, &input, DAY, $part); )*
         }
+
+        /// The parts of the current day, for running it in-process.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                day: DAY,
+                parts: &[$( ($part, |input| $func(input).map(|answer| answer.to_string())) ),*],
+            };
     };
 }
@@ -0,0 +1,77 @@
+/// Solutions compiled into the main binary, to run days in-process instead of spawning a
+/// `cargo run` per day. Every `solution!` exposes its parts as a `SOLUTION` constant, the
+/// `registry` feature collects them with `register_days!` in `src/days.rs`.
+use std::panic::{self, AssertUnwindSafe};
+use std::{env, fs};
+
+use crate::template::Day;
+use crate::template::runner::{PartOutput, measure};
+
+/// A solution part, with its answer already formatted
+pub type PartFn = fn(&str) -> Option<String>;
+
+/// The parts of a single day
+#[derive(Clone, Copy, Debug)]
+pub struct Solution {
+    pub day: Day,
+    pub parts: &'static [(u8, PartFn)],
+}
+
+impl Solution {
+    /// Runs every part on the day's input, benching them if `is_timed` is set.
+    /// Returns no output if the input is missing, and stops at the first part that panics, like
+    /// the solution binary would.
+    pub fn run(&self, is_timed: bool) -> Vec<PartOutput> {
+        let path = env::current_dir()
+            .unwrap()
+            .join("data")
+            .join("inputs")
+            .join(format!("{}.txt", self.day));
+
+        let Ok(input) = fs::read_to_string(&path) else {
+            eprintln!("could not open input file {}", path.display());
+            return vec![];
+        };
+
+        self.parts
+            .iter()
+            .map_while(|(part, func)| {
+                panic::catch_unwind(AssertUnwindSafe(|| {
+                    measure(func, input.as_str(), *part, is_timed)
+                }))
+                .ok()
+            })
+            .collect()
+    }
+}
+
+/// Solutions to run in-process, optionally running days in parallel. Parallel runs finish
+/// sooner, but timings suffer as days compete for the same cores.
+#[derive(Clone, Copy, Debug)]
+pub struct Registry {
+    pub solutions: &'static [Solution],
+    pub parallel: bool,
+}
+
+impl Registry {
+    pub fn get(&self, day: Day) -> Option<&Solution> {
+        self.solutions.iter().find(|s| s.day == day)
+    }
+}
+
+/// Compiles the given solution binaries into the current crate as modules, collecting them in a
+/// `SOLUTIONS` table. Paths are relative to the file invoking the macro.
+#[macro_export]
+macro_rules! register_days {
+    ($($module:ident => $path:literal),* $(,)?) => {
+        $(
+            #[allow(dead_code)]
+            #[path = $path]
+            mod $module;
+        )*
+
+        /// Every registered solution, in the order they were registered.
+        pub const SOLUTIONS: &[$crate::template::registry::Solution] =
+            &[$($module::SOLUTION),*];
+    };
+}
@@ -1,38 +1,87 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+};
+
+use rayon::prelude::*;
 
 use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
 
 use super::{
     all_days,
+    registry::Registry,
+    runner::PartOutput,
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+/// Runs the given days, spawning their solution binaries or, with a registry, in-process.
+/// In-process runs use the profile the runner was built with, so `is_release` only applies to
+/// spawned binaries.
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    registry: Option<Registry>,
+) -> Option<Timings> {
+    if registry.is_some() && is_release == cfg!(debug_assertions) {
+        let profile = if cfg!(debug_assertions) {
+            "debug"
+        } else {
+            "release"
+        };
+        eprintln!(
+            "In-process runs ignore the requested profile, using the {profile} build of the runner."
+        );
+    }
 
-    let mut need_space = false;
+    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let days = all_days()
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+        .collect::<Vec<_>>();
+
+    // parallel runs finish before anything is printed, so output stays in order.
+    let mut finished: HashMap<Day, Vec<PartOutput>> = match registry {
+        Some(registry) if registry.parallel => days
+            .par_iter()
+            .filter_map(|day| Some((*day, registry.get(*day)?.run(is_timed))))
+            .collect(),
+        _ => HashMap::new(),
+    };
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let mut need_space = false;
+
+    days.into_iter().for_each(|day| {
+        if need_space {
+            println!();
+        }
+        need_space = true;
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+        let output = match registry {
+            Some(registry) => {
+                let output = finished.remove(&day).unwrap_or_else(|| {
+                    registry
+                        .get(day)
+                        .map_or_else(Vec::new, |solution| solution.run(is_timed))
+                });
+
+                output.iter().for_each(PartOutput::print);
+                output
             }
-        });
+            None => child_commands::run_solution(day, is_timed, is_release, true).unwrap(),
+        };
+
+        if output.is_empty() {
+            println!("Not solved.");
+        } else {
+            let val = child_commands::parse_exec_time(&output, day);
+            timings.push(val);
+        }
+    });
 
     if is_timed {
         let timings = Timings { data: timings };
@@ -65,10 +114,11 @@ pub fn get_path_for_bin(day: Day) -> String {
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting their results.
 pub mod child_commands {
     use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use crate::template::{Day, runner::PartOutput};
+    use std::time::Duration;
     use std::{
         io::{BufRead, BufReader},
         path::Path,
@@ -76,8 +126,14 @@ pub mod child_commands {
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Run the solution bin for a given day, collecting the output of every part.
+    /// The bin runs with `--json`, with `echo` its results are printed as they come in.
+    pub fn run_solution(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        echo: bool,
+    ) -> Result<Vec<PartOutput>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
             return Ok(vec![]);
@@ -90,14 +146,16 @@ pub mod child_commands {
             args.push("--release");
         }
 
+        args.push("--");
+        args.push("--json");
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr while collecting part results from stdout.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
@@ -118,8 +176,18 @@ pub mod child_commands {
 
         for line in stdout.lines() {
             let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+
+            // anything that is not a part result, like debug output of the solution, is forwarded.
+            match PartOutput::try_from(line.as_str()) {
+                Ok(part) => {
+                    if echo {
+                        part.print();
+                    }
+                    output.push(part);
+                }
+                Err(_) if echo => println!("{line}"),
+                Err(_) => {}
+            }
         }
 
         thread.join().unwrap();
@@ -128,67 +196,39 @@ pub mod child_commands {
         Ok(output)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
+    pub fn parse_exec_time(output: &[PartOutput], day: Day) -> super::Timing {
         let mut timings = super::Timing {
             day,
             part_1: None,
             part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
             total_nanos: 0_f64,
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        // only benched parts count, a single run is too noisy to report.
+        for part in output.iter().filter(|p| p.stats.is_some()) {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let timing_str = format!("{:.1?}", Duration::from_nanos(part.nanos.round() as u64));
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+            match part.part {
+                1 => {
+                    timings.part_1 = Some(timing_str);
+                    timings.part_1_stats = part.stats;
                 }
+                2 => {
+                    timings.part_2 = Some(timing_str);
+                    timings.part_2_stats = part.stats;
+                }
+                _ => continue,
+            }
 
-                timings.total_nanos += nanos;
-            });
+            timings.total_nanos += part.nanos;
+        }
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
     #[cfg(feature = "test_lib")]
     macro_rules! assert_approx_eq {
@@ -207,48 +247,59 @@ pub mod child_commands {
     mod tests {
         use super::parse_exec_time;
 
-        use crate::day;
+        use crate::{
+            day,
+            template::{runner::PartOutput, timings::PartStats},
+        };
+
+        fn benched(part: u8, nanos: f64) -> PartOutput {
+            PartOutput {
+                part,
+                answer: Some("0".into()),
+                nanos,
+                samples: 100,
+                stats: Some(PartStats {
+                    median: nanos,
+                    min: nanos,
+                    p95: nanos,
+                    std_dev: 0.0,
+                    samples: 100,
+                    outliers: 0,
+                }),
+            }
+        }
 
         #[test]
         fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            let res = parse_exec_time(&[benched(1, 74.0), benched(2, 74_130_000.0)], day!(1));
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
+            assert_eq!(res.part_2_stats.unwrap().samples, 100);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+        fn parses_with_patterns_in_answers() {
+            let line = r#"{"part":1,"answer":"Part 1: @ ( ) (2s @ 5 samples)","nanos":2000000000,"samples":5,"stats":{"median":2000000000,"min":2000000000,"p95":2000000000,"std_dev":0,"samples":5,"outliers":0}}"#;
+            let part = PartOutput::try_from(line).unwrap();
+
+            let res = parse_exec_time(&[part], day!(1));
+            assert_approx_eq!(res.total_nanos, 2000000000_f64);
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2, None);
         }
 
         #[test]
         fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
+            let unsolved = |part| PartOutput {
+                part,
+                answer: None,
+                nanos: 10.0,
+                samples: 1,
+                stats: None,
+            };
+
+            let res = parse_exec_time(&[unsolved(1), unsolved(2)], day!(1));
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
@@ -4,21 +4,118 @@ use std::hint::black_box;
 use std::io::{Write, stdout};
 use std::process::Output;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, collections::HashMap, env, process, str::FromStr};
+use tinyjson::JsonValue;
 
 use crate::template::ANSI_BOLD;
+use crate::template::answers::{ANSWERS_FILE_PATH, Answers};
+use crate::template::timings::PartStats;
 use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
 
+/// The outcome of running a single part. With `--json`, solution binaries print one of these per
+/// part as a line of JSON instead of the formatted result, so callers do not have to scrape text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartOutput {
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Duration of the single run, or the median when benched.
+    pub nanos: f64,
+    pub samples: u64,
+    pub stats: Option<PartStats>,
+}
+
+impl PartOutput {
+    /// Prints the part like a solution binary does without `--json`
+    pub fn print(&self) {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let duration = Duration::from_nanos(self.nanos.round() as u64);
+
+        print_result(
+            &self.answer,
+            &format!("Part {}", self.part),
+            &format_duration(&duration, self.stats.as_ref()),
+        );
+    }
+}
+
+fn is_json() -> bool {
+    env::args().any(|x| x == "--json")
+}
+
 pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
+    let is_json = is_json();
+    let is_timed = env::args().any(|x| x == "--time");
+
+    let (result, duration, stats) = run_timed(func, input, is_timed, |result| {
+        if !is_json {
+            print_result(result, &part_str, "");
+
+            if is_timed {
+                print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+                let _ = stdout().flush();
+            }
+        }
+    });
+
+    let output = part_output(part, result.as_ref(), duration, stats);
+
+    if is_json {
+        println!("{}", JsonValue::from(&output).stringify().unwrap());
+    } else {
+        output.print();
+    }
+
+    if let Some(result) = result
+        && let Some(Ok(response)) = submit_result(&result, day, part)
+        && aoc_cli::is_accepted(&response)
+    {
+        record_answer(&result, day, part);
+    }
+}
+
+/// Locks in an accepted answer, so `cargo verify` can check the solution keeps returning it.
+fn record_answer<T: Display>(result: &T, day: Day, part: u8) {
+    let mut answers = match Answers::read_from_file() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to record answer, could not read \"{ANSWERS_FILE_PATH}\": {e}");
+            process::exit(1);
+        }
+    };
+    answers.record(day, part, &result.to_string());
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    match answers.store_file() {
+        Ok(()) => println!("Recorded answer in \"{ANSWERS_FILE_PATH}\"."),
+        Err(e) => eprintln!("Failed to record answer: {e}"),
+    }
+}
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+/// Runs a solution part without printing anything, benching it if `is_timed` is set
+pub fn measure<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    part: u8,
+    is_timed: bool,
+) -> PartOutput {
+    let (result, duration, stats) = run_timed(func, input, is_timed, |_| {});
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    part_output(part, result.as_ref(), duration, stats)
+}
+
+fn part_output<T: Display>(
+    part: u8,
+    result: Option<&T>,
+    duration: Duration,
+    stats: Option<PartStats>,
+) -> PartOutput {
+    #[allow(clippy::cast_precision_loss)]
+    PartOutput {
+        part,
+        answer: result.map(ToString::to_string),
+        nanos: duration.as_nanos() as f64,
+        samples: stats.map_or(1, |s| s.samples),
+        stats,
     }
 }
 
@@ -28,8 +125,9 @@ pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, da
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
+    is_timed: bool,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, Option<PartStats>) {
     let timer = Instant::now();
     let result = {
         #[cfg(feature = "dhat-heap")]
@@ -41,24 +139,25 @@ fn run_timed<I: Copy, T>(
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    if is_timed {
+        let stats = bench(func, input, &base_time);
+        #[allow(clippy::cast_possible_truncation)]
+        let median = Duration::from_nanos(stats.median as u64);
+        (result, median, Some(stats))
     } else {
-        (base_time, 1)
-    };
-
-    (result, run.0, run.1)
+        (result, base_time, None)
+    }
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
-
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
-
+/// Benches the function, discarding a tenth of the iterations as warm-up before collecting samples
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> PartStats {
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
+    for _ in 0..bench_iterations / 10 {
+        black_box(func(black_box(input)));
+    }
+
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
@@ -67,29 +166,74 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    summarize(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+/// Value below which `fraction` of the sorted samples fall, using the nearest rank
+fn percentile(sorted: &[f64], fraction: f64) -> f64 {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let rank = (fraction * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+/// Summarizes bench samples. Samples outside of Tukey's fences, 1.5 times the interquartile
+/// range beyond the first and third quartile, are counted as outliers and left out of the stats
+fn summarize(timers: &[Duration]) -> PartStats {
+    #[allow(clippy::cast_precision_loss)]
+    let mut samples = timers
+        .iter()
+        .map(|t| t.as_nanos() as f64)
+        .collect::<Vec<_>>();
+    samples.sort_unstable_by(f64::total_cmp);
+
+    let (q1, q3) = (percentile(&samples, 0.25), percentile(&samples, 0.75));
+    let fence = 1.5 * (q3 - q1);
+    let kept = samples
+        .iter()
+        .copied()
+        .filter(|s| (q1 - fence..=q3 + fence).contains(s))
+        .collect::<Vec<_>>();
+
+    #[allow(clippy::cast_precision_loss)]
+    let count = kept.len() as f64;
+    let mean = kept.iter().sum::<f64>() / count;
+    let variance = kept.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / count;
+
+    let middle = kept.len() / 2;
+    let median = if kept.len().is_multiple_of(2) {
+        f64::midpoint(kept[middle - 1], kept[middle])
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        kept[middle]
+    };
+
+    PartStats {
+        median,
+        min: kept[0],
+        p95: percentile(&kept, 0.95),
+        std_dev: variance.sqrt(),
+        samples: kept.len() as u64,
+        outliers: (samples.len() - kept.len()) as u64,
     }
 }
 
+fn format_duration(duration: &Duration, stats: Option<&PartStats>) -> String {
+    let Some(stats) = stats else {
+        return format!(" ({duration:.1?})");
+    };
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let nanos = |value: f64| Duration::from_nanos(value.round() as u64);
+
+    format!(
+        " ({duration:.1?} @ {} samples; min {:.1?}, p95 {:.1?}, σ {:.1?}, {} outliers)",
+        stats.samples,
+        nanos(stats.min),
+        nanos(stats.p95),
+        nanos(stats.std_dev),
+        stats.outliers
+    )
+}
+
 fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
@@ -129,7 +273,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 ///  1. we are in `--release` mode.
 ///  2. aoc-cli is installed.
 fn submit_result<T: Display>(
-    result: T,
+    result: &T,
     day: Day,
     part: u8,
 ) -> Option<Result<Output, aoc_cli::AocCommandError>> {
@@ -165,3 +309,109 @@ fn submit_result<T: Display>(
     println!("Submitting result via aoc-cli...");
     Some(aoc_cli::submit(day, part, &result.to_string()))
 }
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartOutput> for JsonValue {
+    fn from(value: &PartOutput) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+
+        if let Some(stats) = value.stats {
+            map.insert("stats".into(), JsonValue::from(stats));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for PartOutput {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("Expected part output to be JSON."))?;
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part output to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected output.{key} to be a number."))
+        };
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected output.answer to be null or string.")?;
+
+        let stats = json.get("stats").map(PartStats::try_from).transpose()?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(PartOutput {
+            part: number("part")? as u8,
+            answer: answer.cloned(),
+            nanos: number("nanos")?,
+            samples: number("samples")? as u64,
+            stats,
+        })
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    use super::{PartOutput, summarize};
+
+    #[test]
+    fn summarizes_samples() {
+        let timers = [5, 1, 4, 2, 3].map(Duration::from_nanos);
+        let stats = summarize(&timers);
+
+        assert_eq!(stats.median, 3.0);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.p95, 5.0);
+        assert_eq!(stats.std_dev, 2_f64.sqrt());
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn roundtrips_part_output() {
+        let output = PartOutput {
+            part: 2,
+            answer: Some("Part 1: 0 (1ms @ 10 samples)\n#.#".into()),
+            nanos: 1500.0,
+            samples: 998,
+            stats: Some(summarize(&[Duration::from_nanos(1500)])),
+        };
+
+        let json = JsonValue::from(&output).stringify().unwrap();
+        assert_eq!(json.lines().count(), 1);
+        assert_eq!(PartOutput::try_from(json.as_str()), Ok(output));
+    }
+
+    #[test]
+    fn excludes_outliers() {
+        let timers = [100, 101, 102, 100, 99, 101, 5000].map(Duration::from_nanos);
+        let stats = summarize(&timers);
+
+        assert_eq!(stats.median, 100.5);
+        assert_eq!(stats.p95, 102.0);
+        assert_eq!(stats.samples, 6);
+        assert_eq!(stats.outliers, 1);
+    }
+}